@@ -0,0 +1,132 @@
+use std::iter::FusedIterator;
+
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::month::Month;
+use crate::util::is_leap_year;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum LeapDayPolicy {
+    #[default]
+    February28,
+    March1,
+}
+
+#[derive(Debug, Clone)]
+pub struct Anniversaries {
+    origin: Date,
+    policy: LeapDayPolicy,
+    year: u16,
+}
+
+impl LeapDayPolicy {
+    pub fn anniversary(&self, origin: &Date, year: u16) -> Result<Date, WBDLError> {
+        let leap_day = origin.month() == Month::February && origin.day().value() == 29;
+        let (month, day) = match self {
+            _ if !leap_day || is_leap_year(year) => (origin.month(), origin.day().value()),
+            LeapDayPolicy::February28 => (Month::February, 28),
+            LeapDayPolicy::March1 => (Month::March, 1),
+        };
+        Date::new(
+            year,
+            month,
+            day,
+            origin.hour().value(),
+            origin.minute().value(),
+            origin.second().value(),
+        )
+    }
+}
+
+impl Date {
+    pub fn age_at(&self, other: &Date) -> Result<u16, WBDLError> {
+        self.age_at_with(other, LeapDayPolicy::default())
+    }
+    pub fn age_at_with(&self, other: &Date, policy: LeapDayPolicy) -> Result<u16, WBDLError> {
+        if other < self {
+            return Err(WBDLError);
+        }
+        let years = other.year() - self.year();
+        if &policy.anniversary(self, other.year())? > other {
+            Ok(years - 1)
+        } else {
+            Ok(years)
+        }
+    }
+    pub fn years_since(&self, origin: &Date) -> Result<u16, WBDLError> {
+        origin.age_at(self)
+    }
+    pub fn anniversaries(&self, policy: LeapDayPolicy) -> Anniversaries {
+        Anniversaries {
+            origin: *self,
+            policy,
+            year: self.year(),
+        }
+    }
+}
+
+impl Anniversaries {
+    pub const fn origin(&self) -> Date {
+        self.origin
+    }
+    pub const fn policy(&self) -> LeapDayPolicy {
+        self.policy
+    }
+}
+
+impl Iterator for Anniversaries {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.year = self.year.checked_add(1)?;
+        self.policy.anniversary(&self.origin, self.year).ok()
+    }
+}
+
+impl FusedIterator for Anniversaries {}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::{Date, LeapDayPolicy};
+
+    #[test]
+    pub fn age() {
+        let birth = date("1990-06-15T00:00:00");
+        assert_eq!(birth.age_at(&date("2024-06-14T23:59:59")), Ok(33));
+        assert_eq!(birth.age_at(&date("2024-06-15T00:00:00")), Ok(34));
+        assert_eq!(date("2024-06-15T00:00:00").years_since(&birth), Ok(34));
+        assert!(birth.age_at(&date("1980-01-01T00:00:00")).is_err());
+    }
+
+    #[test]
+    pub fn leap_day_age() {
+        let birth = date("2004-02-29T00:00:00");
+        let february = date("2005-02-28T12:00:00");
+        assert_eq!(birth.age_at(&february), Ok(1));
+        assert_eq!(birth.age_at_with(&february, LeapDayPolicy::March1), Ok(0));
+        assert_eq!(
+            birth.age_at_with(&date("2005-03-01T00:00:00"), LeapDayPolicy::March1),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    pub fn anniversaries() {
+        let birth = date("2004-02-29T08:00:00");
+        let march: Vec<Date> = birth.anniversaries(LeapDayPolicy::March1).take(4).collect();
+        assert_eq!(
+            march,
+            vec![
+                date("2005-03-01T08:00:00"),
+                date("2006-03-01T08:00:00"),
+                date("2007-03-01T08:00:00"),
+                date("2008-02-29T08:00:00"),
+            ]
+        );
+        assert_eq!(
+            birth.anniversaries(LeapDayPolicy::February28).next(),
+            Some(date("2005-02-28T08:00:00"))
+        );
+    }
+}
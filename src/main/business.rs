@@ -0,0 +1,197 @@
+use std::collections::BTreeSet;
+
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::weekday::Weekday;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BusinessCalendar {
+    weekend: BTreeSet<Weekday>,
+    // stored at midnight so any time of the day matches
+    holidays: BTreeSet<Date>,
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        BusinessCalendar {
+            weekend: BTreeSet::from([Weekday::Saturday, Weekday::Sunday]),
+            holidays: BTreeSet::new(),
+        }
+    }
+}
+
+impl BusinessCalendar {
+    pub fn new() -> BusinessCalendar {
+        BusinessCalendar::default()
+    }
+    pub fn with_weekend(mut self, weekend: &[Weekday]) -> Result<BusinessCalendar, WBDLError> {
+        let weekend: BTreeSet<Weekday> = weekend.iter().copied().collect();
+        if weekend.len() >= 7 {
+            return Err(WBDLError);
+        }
+        self.weekend = weekend;
+        Ok(self)
+    }
+    pub fn with_holiday(mut self, date: Date) -> BusinessCalendar {
+        self.add_holiday(date);
+        self
+    }
+    pub fn with_holidays(mut self, dates: impl IntoIterator<Item = Date>) -> BusinessCalendar {
+        dates.into_iter().for_each(|date| self.add_holiday(date));
+        self
+    }
+    pub fn add_holiday(&mut self, date: Date) {
+        self.holidays.insert(date.reset_until_hours());
+    }
+    pub fn remove_holiday(&mut self, date: &Date) -> bool {
+        self.holidays.remove(&date.reset_until_hours())
+    }
+    pub fn weekend(&self) -> impl Iterator<Item = Weekday> + '_ {
+        self.weekend.iter().copied()
+    }
+    pub fn holidays(&self) -> impl Iterator<Item = Date> + '_ {
+        self.holidays.iter().copied()
+    }
+    pub fn is_weekend(&self, date: &Date) -> bool {
+        self.weekend.contains(&date.weekday())
+    }
+    pub fn is_holiday(&self, date: &Date) -> bool {
+        self.holidays.contains(&date.reset_until_hours())
+    }
+    pub fn is_business_day(&self, date: &Date) -> bool {
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+    // the following business day, keeping the time of day
+    pub fn next_business_day(&self, date: &Date) -> Result<Date, WBDLError> {
+        let mut date = date.add_days(1)?;
+        while !self.is_business_day(&date) {
+            date = date.add_days(1)?;
+        }
+        Ok(date)
+    }
+    pub fn previous_business_day(&self, date: &Date) -> Result<Date, WBDLError> {
+        let mut date = date.add_days(-1)?;
+        while !self.is_business_day(&date) {
+            date = date.add_days(-1)?;
+        }
+        Ok(date)
+    }
+    // adding zero days rolls a non business day forward to the next business day
+    pub fn add_business_days(&self, date: &Date, days: i64) -> Result<Date, WBDLError> {
+        if days == 0 {
+            return if self.is_business_day(date) {
+                Ok(*date)
+            } else {
+                self.next_business_day(date)
+            };
+        }
+        let mut date = *date;
+        for _ in 0..days.unsigned_abs() {
+            date = if days > 0 {
+                self.next_business_day(&date)?
+            } else {
+                self.previous_business_day(&date)?
+            };
+        }
+        Ok(date)
+    }
+    // business days in [start, end), negative if end lies before start
+    pub fn business_days_between(&self, start: &Date, end: &Date) -> i64 {
+        if end < start {
+            return -self.business_days_between(end, start);
+        }
+        let (start, end) = (start.reset_until_hours(), end.reset_until_hours());
+        let days = start.duration_until(&end).as_days();
+        let per_week = 7 - self.weekend.len() as i64;
+        let remainder = (0..days % 7)
+            .filter_map(|offset| start.add_days(days - days % 7 + offset).ok())
+            .filter(|date| !self.is_weekend(date))
+            .count() as i64;
+        let holidays = self
+            .holidays
+            .range(start..end)
+            .filter(|date| !self.is_weekend(date))
+            .count() as i64;
+        days / 7 * per_week + remainder - holidays
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::{BusinessCalendar, Weekday};
+
+    fn calendar() -> BusinessCalendar {
+        BusinessCalendar::new()
+            .with_holiday(date("2023-12-25T00:00:00"))
+            .with_holiday(date("2023-12-26T00:00:00"))
+            .with_holiday(date("2024-01-01T00:00:00"))
+    }
+
+    #[test]
+    pub fn business_days() {
+        let calendar = calendar();
+        assert!(calendar.is_business_day(&date("2023-12-22T15:00:00")));
+        assert!(!calendar.is_business_day(&date("2023-12-23T15:00:00")));
+        assert!(!calendar.is_business_day(&date("2023-12-25T15:00:00")));
+        assert_eq!(
+            calendar.next_business_day(&date("2023-12-22T15:00:00")),
+            Ok(date("2023-12-27T15:00:00"))
+        );
+        assert_eq!(
+            calendar.previous_business_day(&date("2024-01-02T09:00:00")),
+            Ok(date("2023-12-29T09:00:00"))
+        );
+    }
+
+    #[test]
+    pub fn add_business_days() {
+        let calendar = calendar();
+        let friday = date("2023-12-22T10:00:00");
+        assert_eq!(
+            calendar.add_business_days(&friday, 2),
+            Ok(date("2023-12-28T10:00:00"))
+        );
+        assert_eq!(
+            calendar.add_business_days(&date("2023-12-28T10:00:00"), -2),
+            Ok(friday)
+        );
+        assert_eq!(
+            calendar.add_business_days(&date("2023-12-23T10:00:00"), 0),
+            Ok(date("2023-12-27T10:00:00"))
+        );
+    }
+
+    #[test]
+    pub fn business_days_between() {
+        let calendar = calendar();
+        let start = date("2023-12-01T00:00:00");
+        let end = date("2024-01-01T00:00:00");
+        assert_eq!(calendar.business_days_between(&start, &end), 19);
+        assert_eq!(calendar.business_days_between(&end, &start), -19);
+        assert_eq!(
+            BusinessCalendar::new().business_days_between(&start, &end),
+            21
+        );
+    }
+
+    #[test]
+    pub fn custom_weekend() {
+        let calendar = BusinessCalendar::new()
+            .with_weekend(&[Weekday::Friday, Weekday::Saturday])
+            .unwrap();
+        assert!(calendar.is_business_day(&date("2023-12-24T00:00:00")));
+        assert!(!calendar.is_business_day(&date("2023-12-22T00:00:00")));
+        assert!(BusinessCalendar::new()
+            .with_weekend(&[
+                Weekday::Monday,
+                Weekday::Tuesday,
+                Weekday::Wednesday,
+                Weekday::Thursday,
+                Weekday::Friday,
+                Weekday::Saturday,
+                Weekday::Sunday,
+            ])
+            .is_err());
+    }
+}
@@ -0,0 +1,554 @@
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::month::Month;
+use crate::time::Day;
+use crate::util::{civil_from_days, days_from_civil, SECONDS_PER_DAY, UNIX_EPOCH_JULIAN_DAY};
+
+// a date in one of the calendars below, months and days are counted from 1
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct CalendarDate {
+    era: u8,
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+// implement this to convert between Date and another calendar, days count from 1970-01-01
+pub trait Calendar {
+    fn name(&self) -> &str;
+    fn eras(&self) -> &[&str];
+    // 13 names in hebrew leap years
+    fn month_names(&self, era: u8, year: u16) -> &[&str];
+    fn is_leap_year(&self, era: u8, year: u16) -> bool;
+    fn days_in_month(&self, era: u8, year: u16, month: u8) -> u8;
+    fn to_days(&self, date: &CalendarDate) -> Result<i64, WBDLError>;
+    fn on_day(&self, days: i64) -> Result<CalendarDate, WBDLError>;
+    fn date(&self, era: u8, year: u16, month: u8, day: u8) -> Result<CalendarDate, WBDLError> {
+        if era as usize >= self.eras().len() || year == 0 {
+            return Err(WBDLError);
+        }
+        let months = self.month_names(era, year).len() as u8;
+        if month == 0 || month > months || day == 0 || day > self.days_in_month(era, year, month) {
+            return Err(WBDLError);
+        }
+        let date = CalendarDate {
+            era,
+            year,
+            month,
+            day,
+        };
+        // rejects dates outside of their era or before the epoch
+        if self.on_day(self.to_days(&date)?)? != date {
+            return Err(WBDLError);
+        }
+        Ok(date)
+    }
+    // None when the date was made by a different calendar
+    fn era_name(&self, date: &CalendarDate) -> Option<&str> {
+        self.eras().get(date.era as usize).copied()
+    }
+    fn month_name(&self, date: &CalendarDate) -> Option<&str> {
+        let names = self.month_names(date.era, date.year);
+        (date.month as usize)
+            .checked_sub(1)
+            .and_then(|index| names.get(index).copied())
+    }
+    // midnight of that day
+    fn to_date(&self, date: &CalendarDate) -> Result<Date, WBDLError> {
+        Date::from_timestamp(self.to_days(date)? * SECONDS_PER_DAY as i64)
+    }
+    fn on_date(&self, date: &Date) -> Result<CalendarDate, WBDLError> {
+        self.on_day(date.timestamp().div_euclid(SECONDS_PER_DAY as i64))
+    }
+}
+
+impl CalendarDate {
+    pub const fn era(&self) -> u8 {
+        self.era
+    }
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+// only the days a Date can represent, which also keeps the arithmetic below from overflowing
+fn check_days(days: i64) -> Result<i64, WBDLError> {
+    if (days_from_civil(0, 1, 1)..=days_from_civil(u16::MAX, 12, 31)).contains(&days) {
+        Ok(days)
+    } else {
+        Err(WBDLError)
+    }
+}
+
+fn calendar_date(year: i64, month: i64, day: i64) -> Result<CalendarDate, WBDLError> {
+    Ok(CalendarDate {
+        era: 0,
+        year: u16::try_from(year)
+            .ok()
+            .filter(|year| *year > 0)
+            .ok_or(WBDLError)?,
+        month: month as u8,
+        day: day as u8,
+    })
+}
+
+// the arithmetic islamic calendar with the civil epoch 622-07-16 (julian)
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IslamicCalendar;
+
+const ISLAMIC_EPOCH: i64 = 1_948_440 - UNIX_EPOCH_JULIAN_DAY;
+const ISLAMIC_MONTHS: [&str; 12] = [
+    "Muharram",
+    "Safar",
+    "Rabi' al-Awwal",
+    "Rabi' al-Thani",
+    "Jumada al-Awwal",
+    "Jumada al-Thani",
+    "Rajab",
+    "Sha'ban",
+    "Ramadan",
+    "Shawwal",
+    "Dhu al-Qa'dah",
+    "Dhu al-Hijjah",
+];
+
+const fn islamic_days(year: i64, month: i64, day: i64) -> i64 {
+    ISLAMIC_EPOCH - 1 + day + (59 * (month - 1) + 1) / 2 + (year - 1) * 354 + (3 + 11 * year) / 30
+}
+
+impl Calendar for IslamicCalendar {
+    fn name(&self) -> &str {
+        "Islamic"
+    }
+    fn eras(&self) -> &[&str] {
+        &["AH"]
+    }
+    fn month_names(&self, _era: u8, _year: u16) -> &[&str] {
+        &ISLAMIC_MONTHS
+    }
+    // 11 leap years in a cycle of 30
+    fn is_leap_year(&self, _era: u8, year: u16) -> bool {
+        (14 + 11 * year as u32) % 30 < 11
+    }
+    fn days_in_month(&self, era: u8, year: u16, month: u8) -> u8 {
+        if month % 2 == 1 || (month == 12 && self.is_leap_year(era, year)) {
+            30
+        } else {
+            29
+        }
+    }
+    fn to_days(&self, date: &CalendarDate) -> Result<i64, WBDLError> {
+        Ok(islamic_days(
+            date.year as i64,
+            date.month as i64,
+            date.day as i64,
+        ))
+    }
+    fn on_day(&self, days: i64) -> Result<CalendarDate, WBDLError> {
+        let days = check_days(days)?;
+        let year = (30 * (days - ISLAMIC_EPOCH) + 10_646).div_euclid(10_631);
+        let elapsed = days - islamic_days(year, 1, 1);
+        // ceil of elapsed minus 29 over 29.5 days
+        let month = ((2 * (elapsed - 29) + 58).div_euclid(59) + 1).clamp(1, 12);
+        calendar_date(year, month, days - islamic_days(year, month, 1) + 1)
+    }
+}
+
+// the arithmetic hebrew calendar, months are counted from tishrei
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct HebrewCalendar;
+
+// 1 tishrei of year 1 is 3761-10-07 bce (julian)
+const HEBREW_EPOCH: i64 = -2_092_590;
+const HEBREW_MONTHS: [&str; 12] = [
+    "Tishrei", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar", "Nisan", "Iyar", "Sivan", "Tammuz",
+    "Av", "Elul",
+];
+const HEBREW_LEAP_MONTHS: [&str; 13] = [
+    "Tishrei", "Heshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar II", "Nisan", "Iyar",
+    "Sivan", "Tammuz", "Av", "Elul",
+];
+
+const fn hebrew_is_leap_year(year: i64) -> bool {
+    (7 * year + 1).rem_euclid(19) < 7
+}
+
+// days from the epoch to the molad of tishrei, postponed when it falls on sunday, wednesday or friday
+const fn hebrew_elapsed_days(year: i64) -> i64 {
+    let months = (235 * year - 234).div_euclid(19);
+    let parts = 12_084 + 13_753 * months;
+    let days = 29 * months + parts.div_euclid(25_920);
+    if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+const fn hebrew_new_year(year: i64) -> i64 {
+    let previous = hebrew_elapsed_days(year - 1);
+    let current = hebrew_elapsed_days(year);
+    let next = hebrew_elapsed_days(year + 1);
+    let correction = if next - current == 356 {
+        2
+    } else if current - previous == 382 {
+        1
+    } else {
+        0
+    };
+    HEBREW_EPOCH + current + correction
+}
+
+const fn hebrew_days_in_year(year: i64) -> i64 {
+    hebrew_new_year(year + 1) - hebrew_new_year(year)
+}
+
+impl Calendar for HebrewCalendar {
+    fn name(&self) -> &str {
+        "Hebrew"
+    }
+    fn eras(&self) -> &[&str] {
+        &["AM"]
+    }
+    fn month_names(&self, era: u8, year: u16) -> &[&str] {
+        if self.is_leap_year(era, year) {
+            &HEBREW_LEAP_MONTHS
+        } else {
+            &HEBREW_MONTHS
+        }
+    }
+    // 7 leap years in a cycle of 19
+    fn is_leap_year(&self, _era: u8, year: u16) -> bool {
+        hebrew_is_leap_year(year as i64)
+    }
+    fn days_in_month(&self, era: u8, year: u16, month: u8) -> u8 {
+        let leap = self.is_leap_year(era, year);
+        let days_in_year = hebrew_days_in_year(year as i64);
+        // from nisan on the months alternate between 30 and 29 days
+        let nisan = if leap { 8 } else { 7 };
+        match month {
+            1 => 30,
+            2 if days_in_year % 10 == 5 => 30,
+            2 => 29,
+            3 if days_in_year % 10 == 3 => 29,
+            3 => 30,
+            4 => 29,
+            5 => 30,
+            6 if leap => 30,
+            month if month < nisan => 29,
+            month if (month - nisan) % 2 == 0 => 30,
+            _ => 29,
+        }
+    }
+    fn to_days(&self, date: &CalendarDate) -> Result<i64, WBDLError> {
+        let days_before = (1..date.month)
+            .map(|month| self.days_in_month(date.era, date.year, month) as i64)
+            .sum::<i64>();
+        Ok(hebrew_new_year(date.year as i64) + days_before + date.day as i64 - 1)
+    }
+    fn on_day(&self, days: i64) -> Result<CalendarDate, WBDLError> {
+        let days = check_days(days)?;
+        if days < hebrew_new_year(1) {
+            return Err(WBDLError);
+        }
+        // mean year of 35975351 / 98496 days, then corrected by at most one year
+        let mut year = (days - HEBREW_EPOCH) * 98_496 / 35_975_351 + 1;
+        while hebrew_new_year(year + 1) <= days {
+            year += 1;
+        }
+        while hebrew_new_year(year) > days {
+            year -= 1;
+        }
+        let year = u16::try_from(year).map_err(|_err| WBDLError)?;
+        let mut day = days - hebrew_new_year(year as i64) + 1;
+        let mut month = 1;
+        while day > self.days_in_month(0, year, month) as i64 {
+            day -= self.days_in_month(0, year, month) as i64;
+            month += 1;
+        }
+        calendar_date(year as i64, month as i64, day)
+    }
+}
+
+// the arithmetic solar hijri calendar with its 33 year cycle
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PersianCalendar;
+
+// the julian day number the 33 year rule counts from, it matches the modern calendar
+const PERSIAN_EPOCH: i64 = 1_948_320 - UNIX_EPOCH_JULIAN_DAY;
+const PERSIAN_MONTHS: [&str; 12] = [
+    "Farvardin",
+    "Ordibehesht",
+    "Khordad",
+    "Tir",
+    "Mordad",
+    "Shahrivar",
+    "Mehr",
+    "Aban",
+    "Azar",
+    "Dey",
+    "Bahman",
+    "Esfand",
+];
+
+const fn persian_new_year(year: i64) -> i64 {
+    PERSIAN_EPOCH + 365 * (year - 1) + (8 * year + 21).div_euclid(33)
+}
+
+// six months of 31 days, five of 30 and esfand
+const fn persian_days_before(month: i64) -> i64 {
+    if month <= 7 {
+        31 * (month - 1)
+    } else {
+        30 * (month - 1) + 6
+    }
+}
+
+impl Calendar for PersianCalendar {
+    fn name(&self) -> &str {
+        "Persian"
+    }
+    fn eras(&self) -> &[&str] {
+        &["AP"]
+    }
+    fn month_names(&self, _era: u8, _year: u16) -> &[&str] {
+        &PERSIAN_MONTHS
+    }
+    // 8 leap years in a cycle of 33
+    fn is_leap_year(&self, _era: u8, year: u16) -> bool {
+        (25 * year as u32 + 11) % 33 < 8
+    }
+    fn days_in_month(&self, era: u8, year: u16, month: u8) -> u8 {
+        match month {
+            1..=6 => 31,
+            7..=11 => 30,
+            _ if self.is_leap_year(era, year) => 30,
+            _ => 29,
+        }
+    }
+    fn to_days(&self, date: &CalendarDate) -> Result<i64, WBDLError> {
+        Ok(persian_new_year(date.year as i64)
+            + persian_days_before(date.month as i64)
+            + date.day as i64
+            - 1)
+    }
+    fn on_day(&self, days: i64) -> Result<CalendarDate, WBDLError> {
+        let days = check_days(days)?;
+        let year = (33 * (days - PERSIAN_EPOCH) + 3).div_euclid(12_053) + 1;
+        let day_of_year = days - persian_new_year(year);
+        let month = if day_of_year < 186 {
+            day_of_year / 31
+        } else {
+            (day_of_year - 6) / 30
+        } + 1;
+        calendar_date(year, month, day_of_year - persian_days_before(month) + 1)
+    }
+}
+
+// gregorian months and days with years counted per era, from the adoption of the
+// gregorian calendar in meiji 6
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct JapaneseCalendar;
+
+const JAPANESE_ERAS: [&str; 5] = ["Meiji", "Taishō", "Shōwa", "Heisei", "Reiwa"];
+// the first day of every era, meiji starts with the gregorian calendar
+const JAPANESE_ERA_STARTS: [(u16, u8, u8); 5] = [
+    (1873, 1, 1),
+    (1912, 7, 30),
+    (1926, 12, 25),
+    (1989, 1, 8),
+    (2019, 5, 1),
+];
+const JAPANESE_ERA_FIRST_YEARS: [u16; 5] = [1868, 1912, 1926, 1989, 2019];
+const JAPANESE_MONTHS: [&str; 12] = [
+    "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+];
+
+impl JapaneseCalendar {
+    fn gregorian_year(era: u8, year: u16) -> Result<u16, WBDLError> {
+        JAPANESE_ERA_FIRST_YEARS
+            .get(era as usize)
+            .and_then(|first| first.checked_add(year.checked_sub(1)?))
+            .ok_or(WBDLError)
+    }
+}
+
+impl Calendar for JapaneseCalendar {
+    fn name(&self) -> &str {
+        "Japanese"
+    }
+    fn eras(&self) -> &[&str] {
+        &JAPANESE_ERAS
+    }
+    fn month_names(&self, _era: u8, _year: u16) -> &[&str] {
+        &JAPANESE_MONTHS
+    }
+    fn is_leap_year(&self, era: u8, year: u16) -> bool {
+        JapaneseCalendar::gregorian_year(era, year).is_ok_and(crate::util::is_leap_year)
+    }
+    fn days_in_month(&self, era: u8, year: u16, month: u8) -> u8 {
+        match (
+            JapaneseCalendar::gregorian_year(era, year),
+            Month::try_from(month as usize),
+        ) {
+            (Ok(year), Ok(month)) => Day::max(year, month).value(),
+            _ => 0,
+        }
+    }
+    fn to_days(&self, date: &CalendarDate) -> Result<i64, WBDLError> {
+        let year = JapaneseCalendar::gregorian_year(date.era, date.year)?;
+        Ok(days_from_civil(year, date.month, date.day))
+    }
+    fn on_day(&self, days: i64) -> Result<CalendarDate, WBDLError> {
+        let days = check_days(days)?;
+        let era = JAPANESE_ERA_STARTS
+            .iter()
+            .rposition(|(year, month, day)| days_from_civil(*year, *month, *day) <= days)
+            .ok_or(WBDLError)?;
+        let [year, month, day] = civil_from_days(days);
+        let year = year - JAPANESE_ERA_FIRST_YEARS[era] as i64 + 1;
+        Ok(CalendarDate {
+            era: era as u8,
+            ..calendar_date(year, month, day)?
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::{
+        util, Calendar, HebrewCalendar, IslamicCalendar, JapaneseCalendar, JulianDate, Month,
+        PersianCalendar,
+    };
+
+    #[test]
+    pub fn days_out_of_range() {
+        let calendars: [&dyn Calendar; 4] = [
+            &IslamicCalendar,
+            &HebrewCalendar,
+            &PersianCalendar,
+            &JapaneseCalendar,
+        ];
+        for calendar in calendars {
+            for days in [i64::MIN, i64::MIN / 2, i64::MAX / 2, i64::MAX] {
+                assert!(
+                    calendar.on_day(days).is_err(),
+                    "{} {}",
+                    calendar.name(),
+                    days
+                );
+            }
+            // the limits of Date convert or fail, but never panic
+            let _ = calendar.on_day(util::days_from_civil(0, 1, 1));
+            let _ = calendar.on_day(util::days_from_civil(u16::MAX, 12, 31));
+        }
+    }
+
+    fn round_trip(calendar: &dyn Calendar) {
+        let mut value = date("1900-01-01T00:00:00");
+        while value < date("2100-01-01T00:00:00") {
+            let converted = calendar.on_date(&value).unwrap();
+            let checked = calendar
+                .date(
+                    converted.era(),
+                    converted.year(),
+                    converted.month(),
+                    converted.day(),
+                )
+                .unwrap();
+            assert_eq!(calendar.to_date(&checked), Ok(value), "{}", value);
+            value = value.add_days(1).unwrap();
+        }
+    }
+
+    #[test]
+    pub fn islamic() {
+        let calendar = IslamicCalendar;
+        let epoch = JulianDate::new(622, Month::July, 16)
+            .unwrap()
+            .to_date()
+            .unwrap();
+        assert_eq!(
+            calendar
+                .date(0, 1, 1, 1)
+                .and_then(|first| calendar.to_date(&first)),
+            Ok(epoch)
+        );
+        let ramadan = calendar.date(0, 1445, 9, 1).unwrap();
+        assert_eq!(calendar.month_name(&ramadan), Some("Ramadan"));
+        assert_eq!(calendar.to_date(&ramadan), Ok(date("2024-03-11T00:00:00")));
+        assert!(calendar.is_leap_year(0, 1445));
+        assert!(calendar.date(0, 1444, 12, 30).is_err());
+        assert!(calendar.on_date(&date("0600-01-01T00:00:00")).is_err());
+        round_trip(&calendar);
+    }
+
+    #[test]
+    pub fn hebrew() {
+        let calendar = HebrewCalendar;
+        let new_year = calendar.on_date(&date("2023-09-16T00:00:00")).unwrap();
+        assert_eq!(
+            (new_year.year(), new_year.month(), new_year.day()),
+            (5784, 1, 1)
+        );
+        let passover = calendar.date(0, 5784, 8, 15).unwrap();
+        assert_eq!(calendar.month_name(&passover), Some("Nisan"));
+        assert_eq!(calendar.to_date(&passover), Ok(date("2024-04-23T00:00:00")));
+        assert_eq!(calendar.month_names(0, 5785).len(), 12);
+        assert!(calendar.date(0, 5785, 13, 1).is_err());
+        assert_eq!(
+            calendar
+                .date(0, 5785, 1, 1)
+                .and_then(|value| calendar.to_date(&value)),
+            Ok(date("2024-10-03T00:00:00"))
+        );
+        round_trip(&calendar);
+    }
+
+    #[test]
+    pub fn persian() {
+        let calendar = PersianCalendar;
+        let nowruz = calendar.date(0, 1403, 1, 1).unwrap();
+        assert_eq!(calendar.to_date(&nowruz), Ok(date("2024-03-20T00:00:00")));
+        assert!(calendar.is_leap_year(0, 1403));
+        assert_eq!(
+            calendar
+                .date(0, 1403, 12, 30)
+                .and_then(|value| calendar.to_date(&value)),
+            Ok(date("2025-03-20T00:00:00"))
+        );
+        assert!(calendar.date(0, 1402, 12, 30).is_err());
+        let mehr = calendar.on_date(&date("2023-09-23T00:00:00")).unwrap();
+        assert_eq!(calendar.month_name(&mehr), Some("Mehr"));
+        round_trip(&calendar);
+    }
+
+    #[test]
+    pub fn japanese() {
+        let calendar = JapaneseCalendar;
+        let reiwa = calendar.on_date(&date("2019-05-01T12:00:00")).unwrap();
+        assert_eq!(calendar.era_name(&reiwa), Some("Reiwa"));
+        assert_eq!((reiwa.year(), reiwa.month(), reiwa.day()), (1, 5, 1));
+        let heisei = calendar.on_date(&date("2019-04-30T00:00:00")).unwrap();
+        assert_eq!((heisei.era(), heisei.year()), (3, 31));
+        assert!(calendar.date(3, 1, 1, 7).is_err());
+        assert!(calendar.date(2, 64, 1, 7).is_ok());
+        assert!(calendar.date(4, 6, 2, 29).is_ok());
+        assert!(calendar.on_date(&date("1872-12-31T00:00:00")).is_err());
+        assert!(!calendar.is_leap_year(0, 0));
+        assert_eq!(IslamicCalendar.era_name(&reiwa), None);
+        let adar_ii = HebrewCalendar.date(0, 5784, 13, 1).unwrap();
+        assert_eq!(HebrewCalendar.month_name(&adar_ii), Some("Elul"));
+        assert_eq!(PersianCalendar.month_name(&adar_ii), None);
+        assert_eq!(calendar.days_in_month(0, 0, 1), 0);
+        assert!(calendar.date(0, 0, 1, 1).is_err());
+        round_trip(&calendar);
+    }
+}
@@ -0,0 +1,161 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::SystemTime;
+
+use crate::date::Date;
+use crate::duration::Duration;
+use crate::error::WBDLError;
+
+// implement this to control what "now" means, e.g. in tests
+pub trait Clock {
+    fn now(&self) -> Result<Date, WBDLError>;
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FixedClock {
+    date: Date,
+}
+
+// another clock shifted by a constant duration
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct OffsetClock<C: Clock> {
+    clock: C,
+    offset: Duration,
+}
+
+// stands still until it is set or advanced, can be shared between threads
+#[derive(Debug)]
+pub struct ManualClock {
+    timestamp: AtomicI64,
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Result<Date, WBDLError> {
+        Date::try_from(
+            SystemTime::UNIX_EPOCH
+                .elapsed()
+                .map(|va| va.as_secs())
+                .map_err(|_err| WBDLError)?,
+        )
+    }
+}
+
+impl FixedClock {
+    pub const fn new(date: Date) -> FixedClock {
+        FixedClock { date }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> Result<Date, WBDLError> {
+        Ok(self.date)
+    }
+}
+
+impl<C: Clock> OffsetClock<C> {
+    pub const fn new(clock: C, offset: Duration) -> OffsetClock<C> {
+        OffsetClock { clock, offset }
+    }
+    pub const fn offset(&self) -> Duration {
+        self.offset
+    }
+}
+
+impl<C: Clock> Clock for OffsetClock<C> {
+    fn now(&self) -> Result<Date, WBDLError> {
+        self.clock.now()?.add_duration(self.offset)
+    }
+}
+
+impl ManualClock {
+    pub fn new(date: Date) -> ManualClock {
+        ManualClock {
+            timestamp: AtomicI64::new(date.timestamp()),
+        }
+    }
+    pub fn set(&self, date: Date) {
+        self.timestamp.store(date.timestamp(), Ordering::SeqCst);
+    }
+    // fails without moving the clock if the result is not a valid date
+    pub fn advance(&self, duration: Duration) -> Result<Date, WBDLError> {
+        let shift = |timestamp| {
+            Date::from_timestamp(timestamp)
+                .and_then(|date| date.add_duration(duration))
+                .ok()
+        };
+        let previous = self
+            .timestamp
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |timestamp| {
+                shift(timestamp).map(|date| date.timestamp())
+            })
+            .map_err(|_err| WBDLError)?;
+        shift(previous).ok_or(WBDLError)
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Result<Date, WBDLError> {
+        Date::from_timestamp(self.timestamp.load(Ordering::SeqCst))
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Result<Date, WBDLError> {
+        (**self).now()
+    }
+}
+
+impl Date {
+    pub fn now_with(clock: &dyn Clock) -> Result<Date, WBDLError> {
+        clock.now()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::{Clock, Date, Duration, FixedClock, ManualClock, OffsetClock, SystemClock};
+
+    #[test]
+    pub fn fixed_and_offset() {
+        let fixed = FixedClock::new(date("2024-02-28T23:30:00"));
+        assert_eq!(Date::now_with(&fixed), Ok(date("2024-02-28T23:30:00")));
+        let tomorrow = OffsetClock::new(fixed, Duration::from_hours(1));
+        assert_eq!(Date::now_with(&tomorrow), Ok(date("2024-02-29T00:30:00")));
+        let system = Date::now_with(&SystemClock).unwrap();
+        assert!(system >= date("2024-01-01T00:00:00"));
+    }
+
+    #[test]
+    pub fn manual() {
+        let clock = ManualClock::new(date("2024-01-01T00:00:00"));
+        let shifted = OffsetClock::new(&clock, -Duration::from_days(1));
+        assert_eq!(
+            clock.advance(Duration::from_minutes(90)),
+            Ok(date("2024-01-01T01:30:00"))
+        );
+        assert_eq!(clock.now(), Ok(date("2024-01-01T01:30:00")));
+        assert_eq!(shifted.now(), Ok(date("2023-12-31T01:30:00")));
+        clock.set(date("2030-06-01T12:00:00"));
+        assert_eq!(Date::now_with(&clock), Ok(date("2030-06-01T12:00:00")));
+        assert!(clock.advance(Duration::MAX).is_err());
+        assert_eq!(clock.now(), Ok(date("2030-06-01T12:00:00")));
+    }
+
+    #[test]
+    pub fn concurrent_advance() {
+        let clock = ManualClock::new(date("2024-01-01T00:00:00"));
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for _ in 0..100 {
+                        clock.advance(Duration::from_seconds(1)).unwrap();
+                    }
+                });
+            }
+        });
+        assert_eq!(clock.now(), Ok(date("2024-01-01T00:13:20")));
+    }
+}
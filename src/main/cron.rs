@@ -0,0 +1,479 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::month::Month;
+use crate::time::Day;
+use crate::util::days_from_civil;
+use crate::weekday::Weekday;
+
+// the gregorian calendar repeats itself every 400 years
+const SEARCH_YEARS: u16 = 400;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cron {
+    expression: String,
+    seconds: u64,
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    days_any: bool,
+    weekdays_any: bool,
+    // `L` or `L-3`, counted back from the last day of the month
+    last_day: Option<u8>,
+    last_business_day: bool,
+    nearest_weekdays: Vec<u8>,
+    last_weekdays: Vec<u8>,
+    nth_weekdays: Vec<(u8, u8)>,
+}
+
+fn bits(min: u8, max: u8, step: u8) -> u64 {
+    (min..=max)
+        .step_by(step as usize)
+        .fold(0, |bits, value| bits | 1 << value)
+}
+
+fn parse_value(value: &str, names: &[&str], offset: u8) -> Result<u8, WBDLError> {
+    names
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(value))
+        .map(|index| index as u8 + offset)
+        .map(Ok)
+        .unwrap_or_else(|| u8::from_str(value).map_err(|_err| WBDLError))
+}
+
+fn parse_field(
+    field: &str,
+    min: u8,
+    max: u8,
+    names: &[&str],
+    offset: u8,
+) -> Result<u64, WBDLError> {
+    field.split(',').try_fold(0, |result, item| {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, u8::from_str(step).map_err(|_err| WBDLError)?),
+            None => (item, 0),
+        };
+        let (start, end) = match range.split_once('-') {
+            _ if range == "*" => (min, max),
+            Some((start, end)) => (
+                parse_value(start, names, offset)?,
+                parse_value(end, names, offset)?,
+            ),
+            // `5/15` runs from 5 up to the maximum
+            None if step != 0 => (parse_value(range, names, offset)?, max),
+            None => {
+                let value = parse_value(range, names, offset)?;
+                (value, value)
+            }
+        };
+        if start < min || end > max || start > end || (item.contains('/') && step == 0) {
+            return Err(WBDLError);
+        }
+        Ok(result | bits(start, end, step.max(1)))
+    })
+}
+
+impl Cron {
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    fn parse_days(&mut self, field: &str) -> Result<(), WBDLError> {
+        if field == "?" || field == "*" {
+            self.days_any = true;
+            self.days = bits(1, 31, 1);
+            return Ok(());
+        }
+        let mut plain = vec![];
+        for item in field.split(',') {
+            if item == "LW" {
+                self.last_business_day = true;
+            } else if item == "L" {
+                self.last_day = Some(0);
+            } else if let Some(offset) = item.strip_prefix("L-") {
+                let offset = u8::from_str(offset).map_err(|_err| WBDLError)?;
+                if offset > 30 {
+                    return Err(WBDLError);
+                }
+                self.last_day = Some(offset);
+            } else if let Some(day) = item.strip_suffix('W') {
+                let day = u8::from_str(day).map_err(|_err| WBDLError)?;
+                if !(1..=31).contains(&day) {
+                    return Err(WBDLError);
+                }
+                self.nearest_weekdays.push(day);
+            } else {
+                plain.push(item);
+            }
+        }
+        if !plain.is_empty() {
+            self.days = parse_field(&plain.join(","), 1, 31, &[], 0)?;
+        }
+        Ok(())
+    }
+
+    fn parse_weekdays(&mut self, field: &str) -> Result<(), WBDLError> {
+        if field == "?" || field == "*" {
+            self.weekdays_any = true;
+            self.weekdays = bits(0, 6, 1);
+            return Ok(());
+        }
+        let weekday = |value: &str| {
+            parse_value(value, &WEEKDAY_NAMES, 0).and_then(|value| match value {
+                0..=6 => Ok(value),
+                7 => Ok(0),
+                _ => Err(WBDLError),
+            })
+        };
+        let mut plain = vec![];
+        for item in field.split(',') {
+            if let Some((day, nth)) = item.split_once('#') {
+                let nth = u8::from_str(nth).map_err(|_err| WBDLError)?;
+                if !(1..=5).contains(&nth) {
+                    return Err(WBDLError);
+                }
+                self.nth_weekdays.push((weekday(day)?, nth));
+            } else if let Some(day) = item.strip_suffix('L').filter(|day| !day.is_empty()) {
+                self.last_weekdays.push(weekday(day)?);
+            } else {
+                plain.push(item);
+            }
+        }
+        if !plain.is_empty() {
+            let weekdays = parse_field(&plain.join(","), 0, 7, &WEEKDAY_NAMES, 0)?;
+            // 7 is an alias for sunday
+            self.weekdays = (weekdays | weekdays >> 7) & bits(0, 6, 1);
+        }
+        Ok(())
+    }
+
+    fn matches_day(&self, year: u16, month: Month, day: u8) -> bool {
+        let length = Day::max(year, month).value();
+        let weekday = |day: u8| {
+            Weekday::from_days(days_from_civil(year, month as u8, day)).ordinal() as u8 % 7
+        };
+        let current = weekday(day);
+        let nearest_weekday = |target: u8| match weekday(target) {
+            6 if target == 1 => target + 2,
+            6 => target - 1,
+            0 if target == length => target - 2,
+            0 => target + 1,
+            _ => target,
+        };
+        let days = self.days & 1 << day != 0
+            || self
+                .last_day
+                .is_some_and(|offset| offset < length && day == length - offset)
+            || (self.last_business_day && day == nearest_weekday(length))
+            || self
+                .nearest_weekdays
+                .iter()
+                .any(|target| *target <= length && day == nearest_weekday(*target));
+        let weekdays = self.weekdays & 1 << current != 0
+            || self
+                .last_weekdays
+                .iter()
+                .any(|weekday| *weekday == current && day + 7 > length)
+            || self
+                .nth_weekdays
+                .iter()
+                .any(|(weekday, nth)| *weekday == current && (day - 1) / 7 + 1 == *nth);
+        match (self.days_any, self.weekdays_any) {
+            (true, true) => true,
+            (true, false) => weekdays,
+            (false, true) => days,
+            (false, false) => days || weekdays,
+        }
+    }
+
+    pub fn matches(&self, date: &Date) -> bool {
+        self.seconds & 1 << date.second().value() != 0
+            && self.minutes & 1 << date.minute().value() != 0
+            && self.hours & 1 << date.hour().value() != 0
+            && self.months & 1 << date.month().ordinal() != 0
+            && self.matches_day(date.year(), date.month(), date.day().value())
+    }
+
+    // the first fire time strictly after `date`, skipping whole months, days and hours
+    pub fn next_after(&self, date: &Date) -> Option<Date> {
+        let mut cursor = date.add_seconds(1).ok()?;
+        let limit = cursor.year().saturating_add(SEARCH_YEARS);
+        while cursor.year() <= limit {
+            let (year, month, day) = (cursor.year(), cursor.month(), cursor.day().value());
+            let (hour, minute, second) = (
+                cursor.hour().value(),
+                cursor.minute().value(),
+                cursor.second().value(),
+            );
+            cursor = if self.months & 1 << month.ordinal() == 0 {
+                match next_bit(self.months, month.ordinal() as u8, 12) {
+                    Some(next) => Date::new(year, Month::try_from(next as usize).ok()?, 1, 0, 0, 0),
+                    None => Date::new(year.checked_add(1)?, Month::MIN, 1, 0, 0, 0),
+                }
+            } else if !self.matches_day(year, month, day) {
+                cursor.reset_until_hours().add_days(1)
+            } else {
+                match (
+                    next_bit(self.hours, hour, 23),
+                    next_bit(self.minutes, minute, 59),
+                    next_bit(self.seconds, second, 59),
+                ) {
+                    (None, _, _) => cursor.reset_until_hours().add_days(1),
+                    (Some(next), _, _) if next != hour => Date::new(year, month, day, next, 0, 0),
+                    (_, None, _) => cursor.reset_until_minutes().add_hours(1),
+                    (_, Some(next), _) if next != minute => {
+                        Date::new(year, month, day, hour, next, 0)
+                    }
+                    (_, _, None) => cursor.reset_until_seconds().add_minutes(1),
+                    (_, _, Some(next)) if next != second => {
+                        Date::new(year, month, day, hour, minute, next)
+                    }
+                    _ => return Some(cursor),
+                }
+            }
+            .ok()?;
+        }
+        None
+    }
+
+    // the last fire time strictly before `date`
+    pub fn previous_before(&self, date: &Date) -> Option<Date> {
+        let mut cursor = date.add_seconds(-1).ok()?;
+        let limit = cursor.year().saturating_sub(SEARCH_YEARS);
+        while cursor.year() >= limit {
+            let (year, month, day) = (cursor.year(), cursor.month(), cursor.day().value());
+            let (hour, minute, second) = (
+                cursor.hour().value(),
+                cursor.minute().value(),
+                cursor.second().value(),
+            );
+            cursor = if self.months & 1 << month.ordinal() == 0 {
+                match previous_bit(self.months, month.ordinal() as u8) {
+                    Some(previous) => {
+                        let previous = Month::try_from(previous as usize).ok()?;
+                        Date::new(year, previous, Day::max(year, previous).value(), 23, 59, 59)
+                    }
+                    None => Date::new(year.checked_sub(1)?, Month::MAX, 31, 23, 59, 59),
+                }
+            } else if !self.matches_day(year, month, day) {
+                cursor.reset_until_hours().add_seconds(-1)
+            } else {
+                match (
+                    previous_bit(self.hours, hour),
+                    previous_bit(self.minutes, minute),
+                    previous_bit(self.seconds, second),
+                ) {
+                    (None, _, _) => cursor.reset_until_hours().add_seconds(-1),
+                    (Some(previous), _, _) if previous != hour => {
+                        Date::new(year, month, day, previous, 59, 59)
+                    }
+                    (_, None, _) => cursor.reset_until_minutes().add_seconds(-1),
+                    (_, Some(previous), _) if previous != minute => {
+                        Date::new(year, month, day, hour, previous, 59)
+                    }
+                    (_, _, None) => cursor.reset_until_seconds().add_seconds(-1),
+                    (_, _, Some(previous)) if previous != second => {
+                        Date::new(year, month, day, hour, minute, previous)
+                    }
+                    _ => return Some(cursor),
+                }
+            }
+            .ok()?;
+        }
+        None
+    }
+}
+
+fn next_bit(bits: u64, from: u8, max: u8) -> Option<u8> {
+    (from..=max).find(|value| bits & 1 << value != 0)
+}
+
+fn previous_bit(bits: u64, from: u8) -> Option<u8> {
+    (0..=from).rev().find(|value| bits & 1 << value != 0)
+}
+
+impl TryFrom<&str> for Cron {
+    type Error = WBDLError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let expression = match value.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            value => value,
+        };
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let (seconds, fields) = match fields.len() {
+            5 => ("0", &fields[..]),
+            6 => (fields[0], &fields[1..]),
+            _ => return Err(WBDLError),
+        };
+        let mut cron = Cron {
+            expression: value.trim().to_string(),
+            seconds: parse_field(seconds, 0, 59, &[], 0)?,
+            minutes: parse_field(fields[0], 0, 59, &[], 0)?,
+            hours: parse_field(fields[1], 0, 23, &[], 0)?,
+            days: 0,
+            months: parse_field(fields[3], 1, 12, &MONTH_NAMES, 1)?,
+            weekdays: 0,
+            days_any: false,
+            weekdays_any: false,
+            last_day: None,
+            last_business_day: false,
+            nearest_weekdays: vec![],
+            last_weekdays: vec![],
+            nth_weekdays: vec![],
+        };
+        cron.parse_days(fields[2])?;
+        cron.parse_weekdays(fields[4])?;
+        Ok(cron)
+    }
+}
+
+impl FromStr for Cron {
+    type Err = WBDLError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl Display for Cron {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::{Cron, Date};
+
+    fn next(expression: &str, from: &str) -> Option<Date> {
+        Cron::try_from(expression).unwrap().next_after(&date(from))
+    }
+
+    fn previous(expression: &str, from: &str) -> Option<Date> {
+        Cron::try_from(expression)
+            .unwrap()
+            .previous_before(&date(from))
+    }
+
+    #[test]
+    pub fn five_fields() {
+        assert_eq!(
+            next("*/15 9-17 * * MON-FRI", "2024-03-01T17:50:00"),
+            Some(date("2024-03-04T09:00:00"))
+        );
+        assert_eq!(
+            next("0 0 1 1 *", "2024-01-01T00:00:00"),
+            Some(date("2025-01-01T00:00:00"))
+        );
+        assert_eq!(
+            previous("*/15 9-17 * * MON-FRI", "2024-03-04T09:00:00"),
+            Some(date("2024-03-01T17:45:00"))
+        );
+    }
+
+    #[test]
+    pub fn six_fields_and_lists() {
+        assert_eq!(
+            next("30 5,35 * * * *", "2024-12-31T23:35:30"),
+            Some(date("2025-01-01T00:05:30"))
+        );
+        assert_eq!(
+            previous("30 5,35 * * * *", "2025-01-01T00:05:30"),
+            Some(date("2024-12-31T23:35:30"))
+        );
+    }
+
+    #[test]
+    pub fn last_and_nearest_weekday() {
+        assert_eq!(
+            next("0 12 L * ?", "2024-02-01T00:00:00"),
+            Some(date("2024-02-29T12:00:00"))
+        );
+        assert_eq!(
+            next("0 12 L-2 * ?", "2024-02-01T00:00:00"),
+            Some(date("2024-02-27T12:00:00"))
+        );
+        // the 15th of june 2024 is a saturday
+        assert_eq!(
+            next("0 0 15W * ?", "2024-06-01T00:00:00"),
+            Some(date("2024-06-14T00:00:00"))
+        );
+        // the 1st of june 2024 is a saturday as well
+        assert_eq!(
+            next("0 0 1W * ?", "2024-05-31T00:00:00"),
+            Some(date("2024-06-03T00:00:00"))
+        );
+        assert_eq!(
+            next("0 0 LW * ?", "2024-03-01T00:00:00"),
+            Some(date("2024-03-29T00:00:00"))
+        );
+    }
+
+    #[test]
+    pub fn nth_and_last_weekday_of_month() {
+        assert_eq!(
+            next("0 10 ? * 2#1", "2024-09-03T10:00:00"),
+            Some(date("2024-10-01T10:00:00"))
+        );
+        assert_eq!(
+            next("0 10 ? * 5L", "2024-03-01T00:00:00"),
+            Some(date("2024-03-29T10:00:00"))
+        );
+        assert_eq!(
+            previous("0 10 ? * FRIL", "2024-03-01T00:00:00"),
+            Some(date("2024-02-23T10:00:00"))
+        );
+    }
+
+    #[test]
+    pub fn day_of_month_or_day_of_week() {
+        // both restricted means either of them may match
+        assert_eq!(
+            next("0 0 13 * 5", "2024-09-01T00:00:00"),
+            Some(date("2024-09-06T00:00:00"))
+        );
+        assert_eq!(
+            next("0 0 13 * *", "2024-09-01T00:00:00"),
+            Some(date("2024-09-13T00:00:00"))
+        );
+        assert_eq!(
+            next("@monthly", "2024-09-13T00:00:00"),
+            Some(date("2024-10-01T00:00:00"))
+        );
+    }
+
+    #[test]
+    pub fn impossible_and_invalid() {
+        assert_eq!(next("0 0 30 2 *", "2024-01-01T00:00:00"), None);
+        assert_eq!(previous("0 0 30 2 *", "2024-01-01T00:00:00"), None);
+        for expression in [
+            "* * * *",
+            "60 * * * *",
+            "* 24 * * *",
+            "* * 0 * *",
+            "* * * 13 *",
+            "* * * * 8",
+            "*/0 * * * *",
+            "5-1 * * * *",
+            "* * * * MON#6",
+        ] {
+            assert!(Cron::try_from(expression).is_err(), "{}", expression);
+        }
+        assert!(Cron::try_from("0 0 * * 7")
+            .unwrap()
+            .matches(&date("2024-03-03T00:00:00")));
+    }
+}
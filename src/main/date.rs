@@ -3,12 +3,17 @@ use std::fmt::{Debug, Display, Formatter};
 use std::str::FromStr;
 use std::time::SystemTime;
 
+use crate::clock::SystemClock;
 use crate::error::WBDLError;
 use crate::month::Month;
 use crate::time::{Day, Hour, Minute, Second};
-use crate::util::{get_date_time, EPOCH_YEAR};
+use crate::util::{
+    civil_from_days, days_from_civil, get_date_time, EPOCH_YEAR, SECONDS_PER_DAY, SECONDS_PER_HOUR,
+    SECONDS_PER_MINUTE,
+};
+use crate::weekday::Weekday;
 
-#[derive(Eq, Copy, Clone, PartialEq,Hash)]
+#[derive(Eq, Copy, Clone, PartialEq, Hash)]
 pub struct Date {
     day: Day,
     month: Month,
@@ -17,24 +22,28 @@ pub struct Date {
     minute: Minute,
     second: Second,
 }
-impl Default for Date{
+impl Default for Date {
     fn default() -> Self {
         Date::UNIX_EPOCH
     }
 }
 
-impl PartialOrd for Date {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+impl Ord for Date {
+    fn cmp(&self, other: &Self) -> Ordering {
         if self == other {
-            Some(Ordering::Equal)
-        } else if self > other {
-            Some(Ordering::Greater)
+            Ordering::Equal
         } else if self < other {
-            Some(Ordering::Less)
+            Ordering::Less
         } else {
-            None
+            Ordering::Greater
         }
     }
+}
+
+impl PartialOrd for Date {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
     #[allow(clippy::comparison_chain)]
     fn lt(&self, other: &Self) -> bool {
         if self.year < other.year {
@@ -93,34 +102,111 @@ impl Date {
         second: Second::MIN,
         minute: Minute::MIN,
     };
+    pub fn new(
+        year: u16,
+        month: Month,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<Date, WBDLError> {
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(WBDLError);
+        }
+        Ok(Self {
+            day: Day::try_from((day, year, month))?,
+            month,
+            year,
+            hour: Hour::try_from(hour)?,
+            minute: Minute::try_from(minute)?,
+            second: Second::try_from(second)?,
+        })
+    }
+    // like new, but the internet formats allow second 60, read as the first second of the next minute
+    pub(crate) fn with_leap_second(
+        year: u16,
+        month: Month,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<Date, WBDLError> {
+        if second != 60 {
+            return Date::new(year, month, day, hour, minute, second);
+        }
+        Date::new(year, month, day, hour, minute, 59)?.add_seconds(1)
+    }
+    pub fn from_timestamp(timestamp: i64) -> Result<Date, WBDLError> {
+        let seconds = timestamp.rem_euclid(SECONDS_PER_DAY as i64);
+        let [year, month, day] = civil_from_days(timestamp.div_euclid(SECONDS_PER_DAY as i64));
+        Date::new(
+            u16::try_from(year).map_err(|_err| WBDLError)?,
+            Month::try_from(month as usize)?,
+            day as u8,
+            (seconds / SECONDS_PER_HOUR as i64) as u8,
+            (seconds % SECONDS_PER_HOUR as i64 / SECONDS_PER_MINUTE as i64) as u8,
+            (seconds % SECONDS_PER_MINUTE as i64) as u8,
+        )
+    }
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+    pub const fn month(&self) -> Month {
+        self.month
+    }
+    pub const fn day(&self) -> Day {
+        self.day
+    }
+    pub const fn hour(&self) -> Hour {
+        self.hour
+    }
+    pub const fn minute(&self) -> Minute {
+        self.minute
+    }
+    pub const fn second(&self) -> Second {
+        self.second
+    }
+    pub const fn weekday(&self) -> Weekday {
+        Weekday::from_days(days_from_civil(
+            self.year,
+            self.month as u8,
+            self.day.value(),
+        ))
+    }
+    pub const fn day_of_year(&self) -> u16 {
+        (days_from_civil(self.year, self.month as u8, self.day.value())
+            - days_from_civil(self.year, 1, 1)
+            + 1) as u16
+    }
+    pub const fn timestamp(&self) -> i64 {
+        days_from_civil(self.year, self.month as u8, self.day.value()) * SECONDS_PER_DAY as i64
+            + self.hour.value() as i64 * SECONDS_PER_HOUR as i64
+            + self.minute.value() as i64 * SECONDS_PER_MINUTE as i64
+            + self.second.value() as i64
+    }
     pub fn now_unchecked() -> Date {
         Date::now().unwrap()
     }
     pub fn now() -> Result<Date, WBDLError> {
-        Self::try_from(
-            SystemTime::UNIX_EPOCH
-                .elapsed()
-                .map(|va| va.as_secs())
-                .map_err(|_err| WBDLError)?,
-        )
+        Date::now_with(&SystemClock)
     }
     pub fn add_min(mut self) -> Self {
         if self.minute >= Minute::MAX {
-            self.add_hour();
+            self = self.add_hour();
         }
         self.minute = self.minute.next();
         self
     }
-    pub fn add_hour(mut self) -> Self{
+    pub fn add_hour(mut self) -> Self {
         if self.hour >= Hour::MAX {
-            self.add_day();
+            self = self.add_day();
         }
         self.hour = self.hour.next();
         self
     }
     pub fn add_second(mut self) -> Self {
         if self.second >= Second::MAX {
-            self.add_min();
+            self = self.add_min();
         }
         self.second = self.second.next();
         self
@@ -128,14 +214,14 @@ impl Date {
     pub fn add_day(mut self) -> Self {
         let old = (self.year, self.month);
         if self.day >= Day::max(self.year, self.month) {
-            self.add_month();
+            self = self.add_month();
         }
         self.day = self.day.next(old.0, old.1);
         self
     }
     pub fn add_month(mut self) -> Self {
         if self.month.eq(&Month::December) {
-            self.add_year();
+            self = self.add_year();
         }
         self.month = self.month.next();
         self
@@ -145,31 +231,64 @@ impl Date {
         self
     }
     pub fn next_minute(self) -> Self {
-        self.add_min();
-        self.reset_until_seconds()
+        self.add_min().reset_until_seconds()
     }
-    pub fn next_hour(self) -> Self{
-        self.add_hour();
-        self.reset_until_minutes()
+    pub fn next_hour(self) -> Self {
+        self.add_hour().reset_until_minutes()
     }
     pub fn next_day(self) -> Self {
-        self.add_day();
-        self.reset_until_hours()
+        self.add_day().reset_until_hours()
     }
     pub fn next_month(self) -> Self {
-        self.add_month();
-        self.reset_until_days()
+        self.add_month().reset_until_days()
     }
     pub fn next_year(self) -> Self {
-        self.add_year();
-        self.reset_until_months()
+        self.add_year().reset_until_months()
+    }
+    pub fn add_seconds(self, seconds: i64) -> Result<Self, WBDLError> {
+        self.timestamp()
+            .checked_add(seconds)
+            .ok_or(WBDLError)
+            .and_then(Date::from_timestamp)
+    }
+    pub fn add_minutes(self, minutes: i64) -> Result<Self, WBDLError> {
+        self.add_seconds(
+            minutes
+                .checked_mul(SECONDS_PER_MINUTE as i64)
+                .ok_or(WBDLError)?,
+        )
+    }
+    pub fn add_hours(self, hours: i64) -> Result<Self, WBDLError> {
+        self.add_seconds(
+            hours
+                .checked_mul(SECONDS_PER_HOUR as i64)
+                .ok_or(WBDLError)?,
+        )
+    }
+    pub fn add_days(self, days: i64) -> Result<Self, WBDLError> {
+        self.add_seconds(days.checked_mul(SECONDS_PER_DAY as i64).ok_or(WBDLError)?)
+    }
+    pub fn add_weeks(self, weeks: i64) -> Result<Self, WBDLError> {
+        self.add_days(weeks.checked_mul(7).ok_or(WBDLError)?)
+    }
+    pub fn add_months(mut self, months: i64) -> Result<Self, WBDLError> {
+        let total = (self.year as i64 * 12 + self.month.ordinal() as i64 - 1)
+            .checked_add(months)
+            .ok_or(WBDLError)?;
+        self.year = u16::try_from(total.div_euclid(12)).map_err(|_err| WBDLError)?;
+        self.month = Month::try_from(total.rem_euclid(12) as usize + 1)?;
+        self.day = self.day.min(Day::max(self.year, self.month));
+        Ok(self)
+    }
+    pub fn add_years(self, years: i64) -> Result<Self, WBDLError> {
+        self.add_months(years.checked_mul(12).ok_or(WBDLError)?)
     }
     pub const fn reset_until_seconds(mut self) -> Self {
         self.second = Second::MIN;
         self
     }
     pub const fn reset_until_minutes(mut self) -> Self {
-        self.second = Second::MIN;
+        self.minute = Minute::MIN;
         self.reset_until_seconds()
     }
     pub const fn reset_until_hours(mut self) -> Self {
@@ -217,15 +336,21 @@ impl TryFrom<&str> for Date {
             .map(u16::from_str)
             .ok_or(WBDLError)?
             .map_err(|_err| WBDLError)?;
-        let month = Month::try_from(date.next().ok_or(WBDLError)?)?;
-        Ok(Self {
+        // only numeric months, Month::try_from(&str) would also take names
+        let month = Month::try_from(
+            usize::from_str(date.next().ok_or(WBDLError)?).map_err(|_err| WBDLError)?,
+        )?;
+        let number =
+            |part: Option<&str>| u8::from_str(part.ok_or(WBDLError)?).map_err(|_err| WBDLError);
+        let day = number(date.next())?;
+        Date::new(
             year,
             month,
-            day: Day::try_from((date.next().ok_or(WBDLError)?, year, month))?,
-            hour: Hour::try_from(time.next().ok_or(WBDLError)?)?,
-            minute: Minute::try_from(time.next().ok_or(WBDLError)?)?,
-            second: Second::try_from(time.next().ok_or(WBDLError)?)?,
-        })
+            day,
+            number(time.next())?,
+            number(time.next())?,
+            number(time.next())?,
+        )
     }
 }
 
@@ -240,6 +365,17 @@ impl TryFrom<SystemTime> for Date {
     }
 }
 
+impl From<Date> for SystemTime {
+    fn from(value: Date) -> Self {
+        let seconds = std::time::Duration::from_secs(value.timestamp().unsigned_abs());
+        if value.timestamp() < 0 {
+            SystemTime::UNIX_EPOCH - seconds
+        } else {
+            SystemTime::UNIX_EPOCH + seconds
+        }
+    }
+}
+
 impl Iterator for Date {
     type Item = Self;
 
@@ -279,11 +415,12 @@ impl Debug for Date {
 }
 
 #[cfg(test)]
-#[allow(clippy::neg_cmp_op_on_partial_ord)]
+#[allow(clippy::nonminimal_bool)]
 mod tests {
     use std::time::SystemTime;
 
     use crate::date::Date;
+    use crate::Month;
 
     #[test]
     pub fn date_now() {
@@ -299,6 +436,33 @@ mod tests {
         assert_eq!(pre.unwrap(), post.unwrap())
     }
 
+    #[test]
+    pub fn rejects_out_of_range_time() {
+        assert!(Date::new(2024, Month::January, 1, 23, 59, 59).is_ok());
+        assert!(Date::new(2024, Month::January, 1, 24, 0, 0).is_err());
+        assert!(Date::new(2024, Month::January, 1, 0, 60, 0).is_err());
+        assert!(Date::new(2024, Month::January, 1, 0, 0, 60).is_err());
+        assert!(Date::parse_from_str("2024-01-01 24:00:00", "%F %T").is_err());
+        assert!(Date::parse_from_str("2024-01-01 00:00:60", "%F %T").is_err());
+        assert!(Date::try_from("2024-01-01T24:00:00").is_err());
+        assert!(Date::try_from("2024-01-01T00:60:00").is_err());
+        assert!(Date::try_from("2024-01-01T00:00:60").is_err());
+    }
+
+    #[test]
+    pub fn display_is_not_padded() {
+        let date = Date::new(2024, Month::March, 5, 7, 8, 9).unwrap();
+        assert_eq!(date.to_string(), "2024-3-5T7:8:9");
+        assert_eq!(Date::try_from(date.to_string().as_str()), Ok(date));
+    }
+
+    #[test]
+    pub fn month_must_be_numeric() {
+        assert!(Date::try_from("2024-January-01T00:00:00").is_err());
+        assert!(Date::try_from("2024-jan-01T00:00:00").is_err());
+        assert!(Date::try_from("2024-01-01T00:00:00").is_ok());
+    }
+
     #[test]
     pub fn equals() {
         let first = Date::UNIX_EPOCH;
@@ -335,6 +499,49 @@ mod tests {
         assert!(first > second);
     }
 
+    #[test]
+    pub fn reset_until_minutes_clears_minutes() {
+        let date = Date::try_from("2024-01-01T10:20:30").unwrap();
+        assert_eq!(
+            date.reset_until_minutes(),
+            Date::try_from("2024-01-01T10:00:00").unwrap()
+        );
+        assert_eq!(
+            date.reset_until_hours(),
+            Date::try_from("2024-01-01T00:00:00").unwrap()
+        );
+    }
+
+    #[test]
+    pub fn carries_into_next_year() {
+        let last = Date::try_from("1999-12-31T23:59:59").unwrap();
+        assert_eq!(last.add_second(), Date::try_from("2000-1-1T0:0:0").unwrap());
+        assert_eq!(last.next_day(), Date::try_from("2000-1-1T0:0:0").unwrap());
+        assert_eq!(
+            Date::try_from("2024-01-01T10:20:30").unwrap().next_hour(),
+            Date::try_from("2024-01-01T11:00:00").unwrap()
+        );
+    }
+
+    #[test]
+    pub fn timestamp_round_trip() {
+        let date = Date::try_from("2023-12-24T12:12:12").unwrap();
+        assert_eq!(date.timestamp(), 1_703_419_932);
+        assert_eq!(Date::from_timestamp(date.timestamp()), Ok(date));
+        let before = Date::try_from("1969-12-31T23:59:59").unwrap();
+        assert_eq!(before.timestamp(), -1);
+        assert_eq!(Date::from_timestamp(-1), Ok(before));
+    }
+
+    #[test]
+    pub fn add_months_clamps_day() {
+        let date = Date::try_from("2024-1-31T8:0:0").unwrap();
+        assert_eq!(date.add_months(1), Date::try_from("2024-2-29T8:0:0"));
+        assert_eq!(date.add_months(-2), Date::try_from("2023-11-30T8:0:0"));
+        assert_eq!(date.add_years(1), Date::try_from("2025-1-31T8:0:0"));
+        assert!(date.add_years(100_000).is_err());
+    }
+
     #[test]
     pub fn unix_epoch() {
         let first = Date::UNIX_EPOCH;
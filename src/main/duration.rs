@@ -0,0 +1,398 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Neg, Sub};
+use std::str::FromStr;
+
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::util::{SECONDS_PER_DAY, SECONDS_PER_HOUR, SECONDS_PER_MINUTE};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Duration {
+    seconds: i64,
+}
+
+impl Duration {
+    pub const ZERO: Duration = Duration { seconds: 0 };
+    pub const MIN: Duration = Duration { seconds: i64::MIN };
+    pub const MAX: Duration = Duration { seconds: i64::MAX };
+    pub const fn from_seconds(seconds: i64) -> Duration {
+        Duration { seconds }
+    }
+    // these panic when the result does not fit, the checked variants return None instead
+    pub const fn from_minutes(minutes: i64) -> Duration {
+        match Duration::checked_from_minutes(minutes) {
+            Some(duration) => duration,
+            None => panic!("overflow when converting minutes to a duration"),
+        }
+    }
+    pub const fn from_hours(hours: i64) -> Duration {
+        match Duration::checked_from_hours(hours) {
+            Some(duration) => duration,
+            None => panic!("overflow when converting hours to a duration"),
+        }
+    }
+    pub const fn from_days(days: i64) -> Duration {
+        match Duration::checked_from_days(days) {
+            Some(duration) => duration,
+            None => panic!("overflow when converting days to a duration"),
+        }
+    }
+    pub const fn from_weeks(weeks: i64) -> Duration {
+        match Duration::checked_from_weeks(weeks) {
+            Some(duration) => duration,
+            None => panic!("overflow when converting weeks to a duration"),
+        }
+    }
+    const fn checked_from_factor(count: i64, factor: i64) -> Option<Duration> {
+        match count.checked_mul(factor) {
+            Some(seconds) => Some(Duration::from_seconds(seconds)),
+            None => None,
+        }
+    }
+    pub const fn checked_from_minutes(minutes: i64) -> Option<Duration> {
+        Duration::checked_from_factor(minutes, SECONDS_PER_MINUTE as i64)
+    }
+    pub const fn checked_from_hours(hours: i64) -> Option<Duration> {
+        Duration::checked_from_factor(hours, SECONDS_PER_HOUR as i64)
+    }
+    pub const fn checked_from_days(days: i64) -> Option<Duration> {
+        Duration::checked_from_factor(days, SECONDS_PER_DAY as i64)
+    }
+    pub const fn checked_from_weeks(weeks: i64) -> Option<Duration> {
+        Duration::checked_from_factor(weeks, SECONDS_PER_DAY as i64 * 7)
+    }
+    pub const fn as_seconds(&self) -> i64 {
+        self.seconds
+    }
+    pub const fn as_minutes(&self) -> i64 {
+        self.seconds / SECONDS_PER_MINUTE as i64
+    }
+    pub const fn as_hours(&self) -> i64 {
+        self.seconds / SECONDS_PER_HOUR as i64
+    }
+    pub const fn as_days(&self) -> i64 {
+        self.seconds / SECONDS_PER_DAY as i64
+    }
+    pub const fn as_weeks(&self) -> i64 {
+        self.as_days() / 7
+    }
+    pub const fn is_zero(&self) -> bool {
+        self.seconds == 0
+    }
+    pub const fn is_negative(&self) -> bool {
+        self.seconds < 0
+    }
+    // panics for Duration::MIN, its absolute value does not fit
+    pub const fn abs(&self) -> Duration {
+        match self.seconds.checked_abs() {
+            Some(seconds) => Duration { seconds },
+            None => panic!("overflow when taking the absolute value of a duration"),
+        }
+    }
+    pub const fn checked_add(&self, other: Duration) -> Option<Duration> {
+        match self.seconds.checked_add(other.seconds) {
+            Some(seconds) => Some(Duration { seconds }),
+            None => None,
+        }
+    }
+    pub const fn checked_sub(&self, other: Duration) -> Option<Duration> {
+        match self.seconds.checked_sub(other.seconds) {
+            Some(seconds) => Some(Duration { seconds }),
+            None => None,
+        }
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("overflow when adding durations")
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting durations")
+    }
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+    fn neg(self) -> Self::Output {
+        self.seconds
+            .checked_neg()
+            .map(Duration::from_seconds)
+            .expect("overflow when negating duration")
+    }
+}
+
+// the checked constructor for a compact or long english unit name
+fn unit(name: &str) -> Result<fn(i64) -> Option<Duration>, WBDLError> {
+    match name.to_ascii_lowercase().as_str() {
+        "s" | "sec" | "secs" | "second" | "seconds" => {
+            Ok(|count| Some(Duration::from_seconds(count)))
+        }
+        "m" | "min" | "mins" | "minute" | "minutes" => Ok(Duration::checked_from_minutes),
+        "h" | "hr" | "hrs" | "hour" | "hours" => Ok(Duration::checked_from_hours),
+        "d" | "day" | "days" => Ok(Duration::checked_from_days),
+        "w" | "wk" | "wks" | "week" | "weeks" => Ok(Duration::checked_from_weeks),
+        _ => Err(WBDLError),
+    }
+}
+
+// compact like 90s or 1h30m and long like "2 days, 4 hours and 5 minutes"
+impl TryFrom<&str> for Duration {
+    type Error = WBDLError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        let (negative, mut rest) = match value.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, value.strip_prefix('+').unwrap_or(value)),
+        };
+        let mut seconds = 0i64;
+        let mut components = 0;
+        let mut pending = false;
+        loop {
+            rest = rest.trim_start_matches(|char: char| char.is_ascii_whitespace() || char == ',');
+            let letters = rest.bytes().take_while(u8::is_ascii_alphabetic).count();
+            if components > 0 && !pending && rest[..letters].eq_ignore_ascii_case("and") {
+                rest = &rest[letters..];
+                pending = true;
+                continue;
+            }
+            if rest.is_empty() {
+                break;
+            }
+            pending = false;
+            let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+            let count = i64::from_str(&rest[..digits]).map_err(|_err| WBDLError)?;
+            rest = rest[digits..].trim_start();
+            let letters = rest.bytes().take_while(u8::is_ascii_alphabetic).count();
+            let component = unit(&rest[..letters])?;
+            rest = &rest[letters..];
+            seconds = component(count)
+                .and_then(|component| seconds.checked_add(component.seconds))
+                .ok_or(WBDLError)?;
+            components += 1;
+        }
+        if components == 0 || pending {
+            return Err(WBDLError);
+        }
+        Ok(Duration::from_seconds(if negative {
+            -seconds
+        } else {
+            seconds
+        }))
+    }
+}
+
+impl FromStr for Duration {
+    type Err = WBDLError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Duration::try_from(s)
+    }
+}
+
+// compact by default, the alternate flag {:#} prints the long form
+impl Display for Duration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let units = [
+            (SECONDS_PER_DAY as u64, "d", "day"),
+            (SECONDS_PER_HOUR as u64, "h", "hour"),
+            (SECONDS_PER_MINUTE as u64, "m", "minute"),
+            (1, "s", "second"),
+        ];
+        let mut rest = self.seconds.unsigned_abs();
+        let mut parts = vec![];
+        for (size, short, long) in units {
+            let count = rest / size;
+            rest %= size;
+            if count == 0 && !(size == 1 && parts.is_empty()) {
+                continue;
+            }
+            parts.push(if f.alternate() {
+                format!("{} {}{}", count, long, if count == 1 { "" } else { "s" })
+            } else {
+                format!("{}{}", count, short)
+            });
+        }
+        let sign = if self.is_negative() { "-" } else { "" };
+        let separator = if f.alternate() { " " } else { "" };
+        f.pad(&format!("{}{}", sign, parts.join(separator)))
+    }
+}
+
+// sub second precision is truncated
+impl TryFrom<std::time::Duration> for Duration {
+    type Error = WBDLError;
+    fn try_from(value: std::time::Duration) -> Result<Self, Self::Error> {
+        i64::try_from(value.as_secs())
+            .map(Duration::from_seconds)
+            .map_err(|_err| WBDLError)
+    }
+}
+
+// fails for negative durations
+impl TryFrom<Duration> for std::time::Duration {
+    type Error = WBDLError;
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        u64::try_from(value.seconds)
+            .map(std::time::Duration::from_secs)
+            .map_err(|_err| WBDLError)
+    }
+}
+
+impl Date {
+    pub fn add_duration(self, duration: Duration) -> Result<Date, WBDLError> {
+        self.add_seconds(duration.seconds)
+    }
+    pub fn duration_until(&self, other: &Date) -> Duration {
+        Duration::from_seconds(other.timestamp() - self.timestamp())
+    }
+}
+
+impl Add<Duration> for Date {
+    type Output = Date;
+    fn add(self, rhs: Duration) -> Self::Output {
+        self.add_duration(rhs)
+            .expect("overflow when adding duration to date")
+    }
+}
+
+impl Sub<Duration> for Date {
+    type Output = Date;
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self.add_duration(-rhs)
+            .expect("overflow when subtracting duration from date")
+    }
+}
+
+impl Sub for Date {
+    type Output = Duration;
+    fn sub(self, rhs: Self) -> Self::Output {
+        rhs.duration_until(&self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, Duration};
+
+    #[test]
+    pub fn conversions() {
+        let duration = Duration::from_hours(49);
+        assert_eq!(duration.as_seconds(), 176_400);
+        assert_eq!(duration.as_days(), 2);
+        assert_eq!((-duration).as_days(), -2);
+        assert_eq!((-duration).abs(), duration);
+    }
+
+    #[test]
+    pub fn checked_conversions() {
+        assert_eq!(
+            Duration::checked_from_minutes(90),
+            Some(Duration::from_minutes(90))
+        );
+        assert_eq!(
+            Duration::checked_from_weeks(-2),
+            Some(Duration::from_days(-14))
+        );
+        assert_eq!(Duration::checked_from_minutes(i64::MAX), None);
+        assert_eq!(Duration::checked_from_hours(i64::MIN), None);
+        assert_eq!(Duration::checked_from_days(i64::MAX / 86_400 + 1), None);
+        assert_eq!(Duration::checked_from_weeks(i64::MAX / 7), None);
+        assert!("9999999999999999w".parse::<Duration>().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when negating duration")]
+    pub fn negating_min_panics() {
+        let _ = -Duration::MIN;
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when taking the absolute value of a duration")]
+    pub fn abs_of_min_panics() {
+        Duration::MIN.abs();
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when adding durations")]
+    pub fn adding_past_max_panics() {
+        let _ = Duration::MAX + Duration::from_seconds(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when subtracting durations")]
+    pub fn subtracting_past_min_panics() {
+        let _ = Duration::MIN - Duration::from_seconds(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when converting weeks to a duration")]
+    pub fn unchecked_conversion_panics() {
+        Duration::from_weeks(i64::MAX / 7);
+    }
+
+    #[test]
+    pub fn date_arithmetic() {
+        let date = Date::try_from("2024-02-28T23:00:00").unwrap();
+        let later = date + Duration::from_hours(25);
+        assert_eq!(later, Date::try_from("2024-03-01T00:00:00").unwrap());
+        assert_eq!(later - date, Duration::from_hours(25));
+        assert_eq!(later - Duration::from_hours(25), date);
+        assert!(date.add_duration(Duration::MAX).is_err());
+    }
+
+    #[test]
+    pub fn parse() {
+        assert_eq!("90s".parse(), Ok(Duration::from_seconds(90)));
+        assert_eq!(Duration::try_from("1h30m"), Ok(Duration::from_minutes(90)));
+        assert_eq!("2d".parse(), Ok(Duration::from_days(2)));
+        assert_eq!(
+            "2 days, 4 hours and 5 Minutes".parse(),
+            Ok(Duration::from_minutes(2 * 24 * 60 + 4 * 60 + 5))
+        );
+        assert_eq!("-1w".parse(), Ok(-Duration::from_weeks(1)));
+        for invalid in [
+            "",
+            "10",
+            "h",
+            "1y",
+            "and 1h",
+            "1h and",
+            "9999999999999999999s",
+        ] {
+            assert!(invalid.parse::<Duration>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    pub fn display() {
+        let duration = Duration::from_seconds(2 * 86_400 + 4 * 3_600 + 1);
+        assert_eq!(duration.to_string(), "2d4h1s");
+        assert_eq!(format!("{:#}", duration), "2 days 4 hours 1 second");
+        assert_eq!((-Duration::from_minutes(90)).to_string(), "-1h30m");
+        assert_eq!(Duration::ZERO.to_string(), "0s");
+        assert_eq!(format!("{:#}", Duration::ZERO), "0 seconds");
+        assert_eq!(duration.to_string().parse(), Ok(duration));
+        assert_eq!(format!("{:#}", duration).parse(), Ok(duration));
+    }
+
+    #[test]
+    pub fn std_duration() {
+        let duration = Duration::from_minutes(90);
+        let std = std::time::Duration::try_from(duration).unwrap();
+        assert_eq!(std, std::time::Duration::from_secs(5_400));
+        assert_eq!(Duration::try_from(std), Ok(duration));
+        assert!(std::time::Duration::try_from(-duration).is_err());
+        let date = Date::try_from("2024-01-01T00:00:00").unwrap();
+        assert_eq!(
+            date + "1h30m".parse::<Duration>().unwrap(),
+            Date::try_from("2024-01-01T01:30:00").unwrap()
+        );
+    }
+}
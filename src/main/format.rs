@@ -0,0 +1,368 @@
+use std::fmt::Write;
+
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::locale::{Locale, LocaleData};
+use crate::month::Month;
+use crate::time::{Hour, Meridiem};
+use crate::weekday::Weekday;
+
+#[derive(Default)]
+struct Parsed {
+    year: Option<u16>,
+    month: Option<u8>,
+    day: Option<u8>,
+    hour: Option<u8>,
+    hour12: Option<u8>,
+    meridiem: Option<Meridiem>,
+    minute: Option<u8>,
+    second: Option<u8>,
+    day_of_year: Option<u16>,
+    weekday: Option<Weekday>,
+    timestamp: Option<i64>,
+}
+
+// bounds the expansion of locale patterns that refer to each other
+const MAX_PATTERN_DEPTH: u8 = 4;
+
+impl Date {
+    // strftime like: %Y %y %m %b %B %d %e %a %A %H %I %l %p %P %M %S %j %u %s %F %T %r %x %X %c and %%
+    pub fn format(&self, pattern: &str) -> Result<String, WBDLError> {
+        self.format_localized(pattern, &LocaleData::EN)
+    }
+    pub fn format_localized(
+        &self,
+        pattern: &str,
+        locale: &dyn Locale,
+    ) -> Result<String, WBDLError> {
+        let mut out = String::new();
+        self.write_pattern(pattern, locale, 0, &mut out)?;
+        Ok(out)
+    }
+    pub fn parse_from_str(value: &str, pattern: &str) -> Result<Date, WBDLError> {
+        let mut parsed = Parsed::default();
+        let mut input = value;
+        parse_fields(&mut parsed, &mut input, pattern)?;
+        if !input.is_empty() {
+            return Err(WBDLError);
+        }
+        parsed.resolve()
+    }
+    fn write_pattern(
+        &self,
+        pattern: &str,
+        locale: &dyn Locale,
+        depth: u8,
+        out: &mut String,
+    ) -> Result<(), WBDLError> {
+        if depth > MAX_PATTERN_DEPTH {
+            return Err(WBDLError);
+        }
+        let mut chars = pattern.chars();
+        while let Some(char) = chars.next() {
+            if char == '%' {
+                self.write_field(chars.next().ok_or(WBDLError)?, locale, depth, out)?;
+            } else {
+                out.push(char);
+            }
+        }
+        Ok(())
+    }
+    fn write_field(
+        &self,
+        field: char,
+        locale: &dyn Locale,
+        depth: u8,
+        out: &mut String,
+    ) -> Result<(), WBDLError> {
+        let pattern = match field {
+            'F' => "%Y-%m-%d",
+            'T' => "%H:%M:%S",
+            'r' => "%I:%M:%S %p",
+            'x' => locale.date_pattern(),
+            'X' => locale.time_pattern(),
+            'c' => locale.date_time_pattern(),
+            _ => "",
+        };
+        if !pattern.is_empty() {
+            return self.write_pattern(pattern, locale, depth + 1, out);
+        }
+        match field {
+            'Y' => write!(out, "{:04}", self.year()),
+            'y' => write!(out, "{:02}", self.year() % 100),
+            'm' => write!(out, "{:02}", self.month().ordinal()),
+            'b' | 'h' => write!(out, "{}", locale.month_abbreviation(self.month())),
+            'B' => write!(out, "{}", locale.month_name(self.month())),
+            'd' => write!(out, "{:02}", self.day()),
+            'e' => write!(out, "{:>2}", self.day().value()),
+            'H' => write!(out, "{:02}", self.hour()),
+            'I' => write!(out, "{:02}", self.hour().to_12h().0),
+            'l' => write!(out, "{:>2}", self.hour().to_12h().0),
+            'p' => write!(
+                out,
+                "{}",
+                locale.meridiem()[self.hour().meridiem() as usize]
+            ),
+            'P' => write!(
+                out,
+                "{}",
+                locale.meridiem()[self.hour().meridiem() as usize].to_lowercase()
+            ),
+            'M' => write!(out, "{:02}", self.minute()),
+            'S' => write!(out, "{:02}", self.second()),
+            'j' => write!(out, "{:03}", self.day_of_year()),
+            'u' => write!(out, "{}", self.weekday().ordinal()),
+            'a' => write!(out, "{}", locale.weekday_abbreviation(self.weekday())),
+            'A' => write!(out, "{}", locale.weekday_name(self.weekday())),
+            's' => write!(out, "{}", self.timestamp()),
+            '%' => write!(out, "%"),
+            _ => return Err(WBDLError),
+        }
+        .map_err(|_err| WBDLError)
+    }
+}
+
+impl Parsed {
+    // %I needs %p, when %H is given as well all of them have to agree
+    fn hour(&self) -> Result<u8, WBDLError> {
+        let hour = match (self.hour12, self.meridiem) {
+            (Some(hour12), Some(meridiem)) => Hour::from_12h(hour12, meridiem)?.value(),
+            (Some(_), None) => return Err(WBDLError),
+            (None, _) => self.hour.unwrap_or(0),
+        };
+        let hour_matches = self.hour.is_none_or(|value| value == hour);
+        let meridiem_matches = self.meridiem.is_none_or(|meridiem| {
+            Hour::try_from(hour).is_ok_and(|hour| hour.meridiem() == meridiem)
+        });
+        if !hour_matches || !meridiem_matches {
+            return Err(WBDLError);
+        }
+        Ok(hour)
+    }
+    fn resolve(self) -> Result<Date, WBDLError> {
+        let hour = self.hour()?;
+        let date = match (self.timestamp, self.year, self.day_of_year) {
+            (Some(timestamp), _, _) => Date::from_timestamp(timestamp)?,
+            (None, Some(year), Some(day_of_year)) => {
+                let date = Date::new(
+                    year,
+                    Month::January,
+                    1,
+                    hour,
+                    self.minute.unwrap_or(0),
+                    self.second.unwrap_or(0),
+                )?
+                .add_days(day_of_year as i64 - 1)?;
+                let month_matches = self.month.is_none_or(|month| month == date.month() as u8);
+                let day_matches = self.day.is_none_or(|day| day == date.day().value());
+                if date.year() != year || !month_matches || !day_matches {
+                    return Err(WBDLError);
+                }
+                date
+            }
+            (None, Some(year), None) => Date::new(
+                year,
+                Month::try_from(self.month.unwrap_or(1) as usize)?,
+                self.day.unwrap_or(1),
+                hour,
+                self.minute.unwrap_or(0),
+                self.second.unwrap_or(0),
+            )?,
+            (None, None, _) => return Err(WBDLError),
+        };
+        if self
+            .weekday
+            .is_some_and(|weekday| weekday != date.weekday())
+        {
+            return Err(WBDLError);
+        }
+        Ok(date)
+    }
+}
+
+fn take_number(input: &mut &str, max_digits: usize) -> Result<u32, WBDLError> {
+    let digits = input
+        .bytes()
+        .take(max_digits)
+        .take_while(u8::is_ascii_digit)
+        .count();
+    if digits == 0 {
+        return Err(WBDLError);
+    }
+    let (number, rest) = input.split_at(digits);
+    *input = rest;
+    number.parse().map_err(|_err| WBDLError)
+}
+
+fn take_u8(input: &mut &str) -> Result<u8, WBDLError> {
+    take_number(input, 2).map(|number| number as u8)
+}
+
+fn take_word<'a>(input: &mut &'a str) -> &'a str {
+    let length = input.bytes().take_while(u8::is_ascii_alphabetic).count();
+    let (word, rest) = input.split_at(length);
+    *input = rest;
+    word
+}
+
+// am, pm, a.m. or p.m. in any case
+fn take_meridiem(input: &mut &str) -> Result<Meridiem, WBDLError> {
+    for length in [4, 2] {
+        if let Some(meridiem) = input
+            .get(..length)
+            .and_then(|word| Meridiem::try_from(word).ok())
+        {
+            *input = &input[length..];
+            return Ok(meridiem);
+        }
+    }
+    Err(WBDLError)
+}
+
+fn parse_fields(parsed: &mut Parsed, input: &mut &str, pattern: &str) -> Result<(), WBDLError> {
+    let mut chars = pattern.chars();
+    while let Some(char) = chars.next() {
+        if char != '%' {
+            *input = input.strip_prefix(char).ok_or(WBDLError)?;
+            continue;
+        }
+        match chars.next().ok_or(WBDLError)? {
+            'Y' => parsed.year = Some(take_number(input, 4)? as u16),
+            // posix pivot: 69-99 are 19xx, 00-68 are 20xx
+            'y' => {
+                let year = take_number(input, 2)? as u16;
+                parsed.year = Some(if year < 69 { 2000 + year } else { 1900 + year });
+            }
+            'm' => parsed.month = Some(take_u8(input)?),
+            // names are matched case insensitive, full or abbreviated
+            'b' | 'h' | 'B' => parsed.month = Some(Month::from_name(take_word(input))? as u8),
+            'd' => parsed.day = Some(take_u8(input)?),
+            'e' => {
+                *input = input.strip_prefix(' ').unwrap_or(input);
+                parsed.day = Some(take_u8(input)?);
+            }
+            'H' => parsed.hour = Some(take_u8(input)?),
+            'I' => parsed.hour12 = Some(take_u8(input)?),
+            'l' => {
+                *input = input.strip_prefix(' ').unwrap_or(input);
+                parsed.hour12 = Some(take_u8(input)?);
+            }
+            'p' | 'P' => parsed.meridiem = Some(take_meridiem(input)?),
+            'M' => parsed.minute = Some(take_u8(input)?),
+            'S' => parsed.second = Some(take_u8(input)?),
+            'j' => parsed.day_of_year = Some(take_number(input, 3)? as u16),
+            'u' => parsed.weekday = Some(Weekday::try_from(take_number(input, 1)? as usize)?),
+            'a' | 'A' => parsed.weekday = Some(Weekday::from_name(take_word(input))?),
+            's' => {
+                let negative = input.starts_with('-');
+                if negative {
+                    *input = &input[1..];
+                }
+                let digits = input.bytes().take_while(u8::is_ascii_digit).count();
+                let (number, rest) = input.split_at(digits);
+                let timestamp: i64 = number.parse().map_err(|_err| WBDLError)?;
+                parsed.timestamp = Some(if negative { -timestamp } else { timestamp });
+                *input = rest;
+            }
+            'F' => parse_fields(parsed, input, "%Y-%m-%d")?,
+            'T' => parse_fields(parsed, input, "%H:%M:%S")?,
+            'r' => parse_fields(parsed, input, "%I:%M:%S %p")?,
+            '%' => *input = input.strip_prefix('%').ok_or(WBDLError)?,
+            _ => return Err(WBDLError),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Date;
+
+    #[test]
+    pub fn format() {
+        let date = Date::try_from("2024-02-05T07:08:09").unwrap();
+        assert_eq!(
+            date.format("%d.%m.%Y %H:%M:%S").unwrap(),
+            "05.02.2024 07:08:09"
+        );
+        assert_eq!(date.format("%F %T").unwrap(), "2024-02-05 07:08:09");
+        assert_eq!(
+            date.format("%y/%e day %j (%u) 100%%").unwrap(),
+            "24/ 5 day 036 (1) 100%"
+        );
+        assert_eq!(date.format("%s").unwrap(), date.timestamp().to_string());
+        assert_eq!(
+            date.format("%a, %d %b %Y / %A %e %B").unwrap(),
+            "Mon, 05 Feb 2024 / Monday  5 February"
+        );
+        assert!(date.format("%Q").is_err());
+        assert!(date.format("trailing %").is_err());
+    }
+
+    #[test]
+    pub fn parse_from_str() {
+        let date = Date::try_from("2024-02-05T07:08:09").unwrap();
+        assert_eq!(
+            Date::parse_from_str("05.02.2024 07:08:09", "%d.%m.%Y %H:%M:%S"),
+            Ok(date)
+        );
+        assert_eq!(
+            Date::parse_from_str("20240205070809", "%Y%m%d%H%M%S"),
+            Ok(date)
+        );
+        assert_eq!(
+            Date::parse_from_str("24-036", "%y-%j"),
+            Ok(date.reset_until_hours())
+        );
+        assert_eq!(
+            Date::parse_from_str(&date.timestamp().to_string(), "%s"),
+            Ok(date)
+        );
+        assert_eq!(
+            Date::parse_from_str("-86400", "%s"),
+            Date::try_from("1969-12-31T00:00:00")
+        );
+        assert_eq!(
+            Date::parse_from_str("24 Dec 2023", "%d %b %Y"),
+            Date::try_from("2023-12-24T00:00:00")
+        );
+        assert_eq!(
+            Date::parse_from_str("sunday, 24 DECEMBER 2023 12:12:12", "%A, %d %B %Y %T"),
+            Date::try_from("2023-12-24T12:12:12")
+        );
+        assert!(Date::parse_from_str("Mon 24 Dec 2023", "%a %d %b %Y").is_err());
+        assert!(Date::parse_from_str("2024-02-05 (2)", "%F (%u)").is_err());
+        assert!(Date::parse_from_str("2024-02-30", "%F").is_err());
+        assert!(Date::parse_from_str("2024-02-05 extra", "%F").is_err());
+        assert!(Date::parse_from_str("07:08", "%H:%M").is_err());
+    }
+
+    #[test]
+    pub fn twelve_hour_clock() {
+        let date = Date::try_from("2024-02-05T00:30:09").unwrap();
+        assert_eq!(date.format("%I:%M %p").unwrap(), "12:30 AM");
+        assert_eq!(
+            date.add_hours(13).unwrap().format("%l:%M %P").unwrap(),
+            " 1:30 pm"
+        );
+        assert_eq!(
+            date.add_hours(12).unwrap().format("%r").unwrap(),
+            "12:30:09 PM"
+        );
+        assert_eq!(
+            Date::parse_from_str("2024-02-05 12:30 PM", "%F %I:%M %p"),
+            Date::try_from("2024-02-05T12:30:00")
+        );
+        assert_eq!(
+            Date::parse_from_str("2024-02-05 7:08:09 a.m.", "%F %l:%M:%S %p"),
+            Date::try_from("2024-02-05T07:08:09")
+        );
+        assert_eq!(
+            Date::parse_from_str("2024-02-05 19:08 pm", "%F %H:%M %p"),
+            Date::try_from("2024-02-05T19:08:00")
+        );
+        assert!(Date::parse_from_str("2024-02-05 12:30", "%F %I:%M").is_err());
+        assert!(Date::parse_from_str("2024-02-05 13:30 PM", "%F %I:%M %p").is_err());
+        assert!(Date::parse_from_str("2024-02-05 07:08 pm", "%F %H:%M %p").is_err());
+    }
+}
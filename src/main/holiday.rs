@@ -0,0 +1,573 @@
+use std::ops::RangeInclusive;
+
+use crate::business::BusinessCalendar;
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::month::Month;
+use crate::util;
+use crate::weekday::Weekday;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HolidayRule {
+    Fixed {
+        month: Month,
+        day: u8,
+    },
+    // a negative `n` counts from the end of the month, -1 being the last one
+    NthWeekday {
+        month: Month,
+        weekday: Weekday,
+        n: i8,
+    },
+    EasterOffset(i16),
+    OrthodoxEasterOffset(i16),
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum MoveableFeast {
+    AshWednesday,
+    PalmSunday,
+    MaundyThursday,
+    GoodFriday,
+    HolySaturday,
+    EasterSunday,
+    EasterMonday,
+    Ascension,
+    Pentecost,
+    WhitMonday,
+    TrinitySunday,
+    CorpusChristi,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Observance {
+    #[default]
+    Actual,
+    NearestWeekday,
+    FollowingMonday,
+    SundayToMonday,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Holiday {
+    name: String,
+    rule: HolidayRule,
+    observance: Observance,
+    first_year: Option<u16>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct HolidaySet {
+    holidays: Vec<Holiday>,
+}
+
+fn nth_weekday(year: u16, month: Month, weekday: Weekday, n: i8) -> Result<Date, WBDLError> {
+    if n >= 0 {
+        return Date::nth_weekday_of_month(year, month, weekday, n as u8);
+    }
+    let date = Date::last_weekday_of_month(year, month, weekday)?.add_weeks(n as i64 + 1)?;
+    if date.month() != month {
+        return Err(WBDLError);
+    }
+    Ok(date)
+}
+
+impl HolidayRule {
+    pub fn date(&self, year: u16) -> Result<Date, WBDLError> {
+        match self {
+            HolidayRule::Fixed { month, day } => Date::new(year, *month, *day, 0, 0, 0),
+            HolidayRule::NthWeekday { month, weekday, n } => {
+                nth_weekday(year, *month, *weekday, *n)
+            }
+            HolidayRule::EasterOffset(offset) => Date::easter(year)?.add_days(*offset as i64),
+            HolidayRule::OrthodoxEasterOffset(offset) => {
+                Date::orthodox_easter(year)?.add_days(*offset as i64)
+            }
+        }
+    }
+}
+
+impl MoveableFeast {
+    // days relative to easter sunday
+    pub const fn offset(&self) -> i16 {
+        match self {
+            MoveableFeast::AshWednesday => -46,
+            MoveableFeast::PalmSunday => -7,
+            MoveableFeast::MaundyThursday => -3,
+            MoveableFeast::GoodFriday => -2,
+            MoveableFeast::HolySaturday => -1,
+            MoveableFeast::EasterSunday => 0,
+            MoveableFeast::EasterMonday => 1,
+            MoveableFeast::Ascension => 39,
+            MoveableFeast::Pentecost => 49,
+            MoveableFeast::WhitMonday => 50,
+            MoveableFeast::TrinitySunday => 56,
+            MoveableFeast::CorpusChristi => 60,
+        }
+    }
+    pub const fn rule(&self) -> HolidayRule {
+        HolidayRule::EasterOffset(self.offset())
+    }
+    pub fn date(&self, year: u16) -> Result<Date, WBDLError> {
+        Date::easter(year)?.add_days(self.offset() as i64)
+    }
+    pub fn orthodox_date(&self, year: u16) -> Result<Date, WBDLError> {
+        Date::orthodox_easter(year)?.add_days(self.offset() as i64)
+    }
+}
+
+impl Date {
+    pub fn easter(year: u16) -> Result<Date, WBDLError> {
+        let [month, day] = util::easter(year);
+        Date::new(year, Month::try_from(month as usize)?, day, 0, 0, 0)
+    }
+    pub fn orthodox_easter(year: u16) -> Result<Date, WBDLError> {
+        let [month, day] = util::orthodox_easter(year);
+        Date::new(year, Month::try_from(month as usize)?, day, 0, 0, 0)
+    }
+}
+
+impl Observance {
+    pub fn apply(&self, date: Date) -> Result<Date, WBDLError> {
+        match (self, date.weekday()) {
+            (Observance::NearestWeekday, Weekday::Saturday) => date.add_days(-1),
+            (Observance::NearestWeekday, Weekday::Sunday) => date.add_days(1),
+            (Observance::FollowingMonday, Weekday::Saturday) => date.add_days(2),
+            (Observance::FollowingMonday | Observance::SundayToMonday, Weekday::Sunday) => {
+                date.add_days(1)
+            }
+            _ => Ok(date),
+        }
+    }
+}
+
+impl Holiday {
+    pub fn new(name: impl Into<String>, rule: HolidayRule) -> Holiday {
+        Holiday {
+            name: name.into(),
+            rule,
+            observance: Observance::Actual,
+            first_year: None,
+        }
+    }
+    pub fn with_observance(mut self, observance: Observance) -> Holiday {
+        self.observance = observance;
+        self
+    }
+    pub fn with_first_year(mut self, year: u16) -> Holiday {
+        self.first_year = Some(year);
+        self
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub const fn rule(&self) -> HolidayRule {
+        self.rule
+    }
+    pub const fn observance(&self) -> Observance {
+        self.observance
+    }
+    pub const fn first_year(&self) -> Option<u16> {
+        self.first_year
+    }
+    pub fn is_observed_in(&self, year: u16) -> bool {
+        self.first_year.is_none_or(|first| first <= year)
+    }
+    pub fn actual_date(&self, year: u16) -> Result<Date, WBDLError> {
+        if !self.is_observed_in(year) {
+            return Err(WBDLError);
+        }
+        self.rule.date(year)
+    }
+    // may fall into the neighbouring year, e.g. a saturday new year observed on friday
+    pub fn date(&self, year: u16) -> Result<Date, WBDLError> {
+        self.observance.apply(self.actual_date(year)?)
+    }
+}
+
+impl HolidaySet {
+    pub const fn new() -> HolidaySet {
+        HolidaySet { holidays: vec![] }
+    }
+    pub fn with(mut self, holiday: Holiday) -> HolidaySet {
+        self.holidays.push(holiday);
+        self
+    }
+    pub fn holidays(&self) -> &[Holiday] {
+        &self.holidays
+    }
+    pub fn dates(&self, year: u16) -> Vec<(&Holiday, Date)> {
+        let mut dates: Vec<(&Holiday, Date)> = self
+            .holidays
+            .iter()
+            .filter_map(|holiday| holiday.date(year).ok().map(|date| (holiday, date)))
+            .collect();
+        dates.sort_by_key(|(_, date)| *date);
+        dates
+    }
+    pub fn holiday_on(&self, date: &Date) -> Option<&Holiday> {
+        let day = date.reset_until_hours();
+        // observed dates can move across new year
+        [
+            date.year().checked_sub(1),
+            Some(date.year()),
+            date.year().checked_add(1),
+        ]
+        .into_iter()
+        .flatten()
+        .flat_map(|year| self.dates(year))
+        .find(|(_, date)| date == &day)
+        .map(|(holiday, _)| holiday)
+    }
+    pub fn is_holiday(&self, date: &Date) -> bool {
+        self.holiday_on(date).is_some()
+    }
+
+    pub fn us_federal() -> HolidaySet {
+        use HolidayRule::{Fixed, NthWeekday};
+        let nearest = Observance::NearestWeekday;
+        HolidaySet::new()
+            .with(
+                Holiday::new(
+                    "New Year's Day",
+                    Fixed {
+                        month: Month::January,
+                        day: 1,
+                    },
+                )
+                .with_observance(nearest),
+            )
+            .with(Holiday::new(
+                "Birthday of Martin Luther King, Jr.",
+                NthWeekday {
+                    month: Month::January,
+                    weekday: Weekday::Monday,
+                    n: 3,
+                },
+            ))
+            .with(Holiday::new(
+                "Washington's Birthday",
+                NthWeekday {
+                    month: Month::February,
+                    weekday: Weekday::Monday,
+                    n: 3,
+                },
+            ))
+            .with(Holiday::new(
+                "Memorial Day",
+                NthWeekday {
+                    month: Month::May,
+                    weekday: Weekday::Monday,
+                    n: -1,
+                },
+            ))
+            .with(
+                Holiday::new(
+                    "Juneteenth National Independence Day",
+                    Fixed {
+                        month: Month::June,
+                        day: 19,
+                    },
+                )
+                .with_observance(nearest)
+                .with_first_year(2021),
+            )
+            .with(
+                Holiday::new(
+                    "Independence Day",
+                    Fixed {
+                        month: Month::July,
+                        day: 4,
+                    },
+                )
+                .with_observance(nearest),
+            )
+            .with(Holiday::new(
+                "Labor Day",
+                NthWeekday {
+                    month: Month::September,
+                    weekday: Weekday::Monday,
+                    n: 1,
+                },
+            ))
+            .with(Holiday::new(
+                "Columbus Day",
+                NthWeekday {
+                    month: Month::October,
+                    weekday: Weekday::Monday,
+                    n: 2,
+                },
+            ))
+            .with(
+                Holiday::new(
+                    "Veterans Day",
+                    Fixed {
+                        month: Month::November,
+                        day: 11,
+                    },
+                )
+                .with_observance(nearest),
+            )
+            .with(Holiday::new(
+                "Thanksgiving Day",
+                NthWeekday {
+                    month: Month::November,
+                    weekday: Weekday::Thursday,
+                    n: 4,
+                },
+            ))
+            .with(
+                Holiday::new(
+                    "Christmas Day",
+                    Fixed {
+                        month: Month::December,
+                        day: 25,
+                    },
+                )
+                .with_observance(nearest),
+            )
+    }
+
+    pub fn germany() -> HolidaySet {
+        use HolidayRule::Fixed;
+        HolidaySet::new()
+            .with(Holiday::new(
+                "Neujahr",
+                Fixed {
+                    month: Month::January,
+                    day: 1,
+                },
+            ))
+            .with(Holiday::new("Karfreitag", MoveableFeast::GoodFriday.rule()))
+            .with(Holiday::new(
+                "Ostermontag",
+                MoveableFeast::EasterMonday.rule(),
+            ))
+            .with(Holiday::new(
+                "Tag der Arbeit",
+                Fixed {
+                    month: Month::May,
+                    day: 1,
+                },
+            ))
+            .with(Holiday::new(
+                "Christi Himmelfahrt",
+                MoveableFeast::Ascension.rule(),
+            ))
+            .with(Holiday::new(
+                "Pfingstmontag",
+                MoveableFeast::WhitMonday.rule(),
+            ))
+            .with(
+                Holiday::new(
+                    "Tag der Deutschen Einheit",
+                    Fixed {
+                        month: Month::October,
+                        day: 3,
+                    },
+                )
+                .with_first_year(1990),
+            )
+            .with(Holiday::new(
+                "Erster Weihnachtstag",
+                Fixed {
+                    month: Month::December,
+                    day: 25,
+                },
+            ))
+            .with(Holiday::new(
+                "Zweiter Weihnachtstag",
+                Fixed {
+                    month: Month::December,
+                    day: 26,
+                },
+            ))
+    }
+
+    pub fn france() -> HolidaySet {
+        use HolidayRule::Fixed;
+        HolidaySet::new()
+            .with(Holiday::new(
+                "Jour de l'an",
+                Fixed {
+                    month: Month::January,
+                    day: 1,
+                },
+            ))
+            .with(Holiday::new(
+                "Lundi de Pâques",
+                MoveableFeast::EasterMonday.rule(),
+            ))
+            .with(Holiday::new(
+                "Fête du Travail",
+                Fixed {
+                    month: Month::May,
+                    day: 1,
+                },
+            ))
+            .with(Holiday::new(
+                "Victoire 1945",
+                Fixed {
+                    month: Month::May,
+                    day: 8,
+                },
+            ))
+            .with(Holiday::new("Ascension", MoveableFeast::Ascension.rule()))
+            .with(Holiday::new(
+                "Lundi de Pentecôte",
+                MoveableFeast::WhitMonday.rule(),
+            ))
+            .with(Holiday::new(
+                "Fête nationale",
+                Fixed {
+                    month: Month::July,
+                    day: 14,
+                },
+            ))
+            .with(Holiday::new(
+                "Assomption",
+                Fixed {
+                    month: Month::August,
+                    day: 15,
+                },
+            ))
+            .with(Holiday::new(
+                "Toussaint",
+                Fixed {
+                    month: Month::November,
+                    day: 1,
+                },
+            ))
+            .with(Holiday::new(
+                "Armistice 1918",
+                Fixed {
+                    month: Month::November,
+                    day: 11,
+                },
+            ))
+            .with(Holiday::new(
+                "Noël",
+                Fixed {
+                    month: Month::December,
+                    day: 25,
+                },
+            ))
+    }
+}
+
+impl BusinessCalendar {
+    pub fn with_holiday_set(
+        self,
+        holidays: &HolidaySet,
+        years: RangeInclusive<u16>,
+    ) -> BusinessCalendar {
+        let dates: Vec<Date> = years
+            .flat_map(|year| holidays.dates(year))
+            .map(|(_, date)| date)
+            .collect();
+        self.with_holidays(dates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::{
+        BusinessCalendar, Date, Holiday, HolidayRule, HolidaySet, Month, MoveableFeast, Observance,
+        Weekday,
+    };
+
+    #[test]
+    pub fn rules() {
+        let last_monday = HolidayRule::NthWeekday {
+            month: Month::May,
+            weekday: Weekday::Monday,
+            n: -1,
+        };
+        assert_eq!(last_monday.date(2024), Ok(date("2024-05-27T00:00:00")));
+        assert_eq!(
+            HolidayRule::EasterOffset(0).date(2024),
+            Ok(date("2024-03-31T00:00:00"))
+        );
+        assert_eq!(
+            HolidayRule::EasterOffset(-2).date(2025),
+            Ok(date("2025-04-18T00:00:00"))
+        );
+        let fifth_friday = HolidayRule::NthWeekday {
+            month: Month::February,
+            weekday: Weekday::Friday,
+            n: 5,
+        };
+        assert!(fifth_friday.date(2024).is_err());
+    }
+
+    #[test]
+    pub fn moveable_feasts() {
+        assert_eq!(Date::easter(2024), Ok(date("2024-03-31T00:00:00")));
+        assert_eq!(Date::orthodox_easter(2024), Ok(date("2024-05-05T00:00:00")));
+        assert_eq!(
+            MoveableFeast::GoodFriday.date(2024),
+            Ok(date("2024-03-29T00:00:00"))
+        );
+        assert_eq!(
+            MoveableFeast::Pentecost.date(2025),
+            Ok(date("2025-06-08T00:00:00"))
+        );
+        assert_eq!(
+            MoveableFeast::AshWednesday.date(2025),
+            Ok(date("2025-03-05T00:00:00"))
+        );
+        assert_eq!(
+            MoveableFeast::Ascension.orthodox_date(2024),
+            Ok(date("2024-06-13T00:00:00"))
+        );
+    }
+
+    #[test]
+    pub fn observance() {
+        let independence = Holiday::new(
+            "Independence Day",
+            HolidayRule::Fixed {
+                month: Month::July,
+                day: 4,
+            },
+        )
+        .with_observance(Observance::NearestWeekday);
+        assert_eq!(independence.date(2026), Ok(date("2026-07-03T00:00:00")));
+        assert_eq!(independence.date(2027), Ok(date("2027-07-05T00:00:00")));
+        assert_eq!(
+            independence.actual_date(2027),
+            Ok(date("2027-07-04T00:00:00"))
+        );
+    }
+
+    #[test]
+    pub fn us_federal() {
+        let holidays = HolidaySet::us_federal();
+        let dates: Vec<Date> = holidays
+            .dates(2024)
+            .into_iter()
+            .map(|(_, date)| date)
+            .collect();
+        assert_eq!(dates.len(), 11);
+        assert_eq!(dates[1], date("2024-01-15T00:00:00"));
+        assert_eq!(dates[9], date("2024-11-28T00:00:00"));
+        assert_eq!(HolidaySet::us_federal().dates(2020).len(), 10);
+        // new year 2022 was a saturday and observed on the last day of 2021
+        assert_eq!(
+            holidays
+                .holiday_on(&date("2021-12-31T12:00:00"))
+                .map(Holiday::name),
+            Some("New Year's Day")
+        );
+    }
+
+    #[test]
+    pub fn germany_in_business_calendar() {
+        let calendar =
+            BusinessCalendar::new().with_holiday_set(&HolidaySet::germany(), 2024..=2024);
+        assert!(!calendar.is_business_day(&date("2024-05-09T00:00:00")));
+        assert!(!calendar.is_business_day(&date("2024-05-20T00:00:00")));
+        assert!(calendar.is_business_day(&date("2024-05-21T00:00:00")));
+    }
+}
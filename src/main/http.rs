@@ -0,0 +1,254 @@
+use std::str::FromStr;
+
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::month::Month;
+use crate::weekday::Weekday;
+
+fn digits(value: &str) -> Result<u16, WBDLError> {
+    if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(WBDLError);
+    }
+    u16::from_str(value).map_err(|_err| WBDLError)
+}
+
+// names are case sensitive in RFC 9110
+fn month(value: &str) -> Result<Month, WBDLError> {
+    Month::from_abbreviation(value)
+        .ok()
+        .filter(|month| month.abbreviation() == value)
+        .ok_or(WBDLError)
+}
+
+fn weekday(value: &str, full: bool) -> Result<Weekday, WBDLError> {
+    (1..=7)
+        .filter_map(|weekday| Weekday::try_from(weekday).ok())
+        .find(|weekday| {
+            value
+                == if full {
+                    weekday.name()
+                } else {
+                    weekday.abbreviation()
+                }
+        })
+        .ok_or(WBDLError)
+}
+
+// HH:MM:SS, a leap second is allowed
+fn time(value: &str) -> Result<[u8; 3], WBDLError> {
+    let bytes = value.as_bytes();
+    if bytes.len() != 8 || bytes[2] != b':' || bytes[5] != b':' {
+        return Err(WBDLError);
+    }
+    let time = [
+        digits(&value[0..2])? as u8,
+        digits(&value[3..5])? as u8,
+        digits(&value[6..8])? as u8,
+    ];
+    if time[0] > 23 || time[1] > 59 || time[2] > 60 {
+        return Err(WBDLError);
+    }
+    Ok(time)
+}
+
+fn build(
+    weekday: Weekday,
+    year: u16,
+    month: Month,
+    day: u16,
+    time: [u8; 3],
+) -> Result<Date, WBDLError> {
+    let [hour, minute, second] = time;
+    // the weekday belongs to the day as written, before a leap second moves it on
+    if Date::new(year, month, day as u8, 0, 0, 0)?.weekday() != weekday {
+        return Err(WBDLError);
+    }
+    Date::with_leap_second(year, month, day as u8, hour, minute, second)
+}
+
+// Sun, 06 Nov 1994 08:49:37 GMT
+fn imf_fixdate(value: &str) -> Result<Date, WBDLError> {
+    let bytes = value.as_bytes();
+    if bytes.len() != 29 || &value[3..5] != ", " || &value[25..] != " GMT" {
+        return Err(WBDLError);
+    }
+    if bytes[7] != b' ' || bytes[11] != b' ' || bytes[16] != b' ' {
+        return Err(WBDLError);
+    }
+    build(
+        weekday(&value[..3], false)?,
+        digits(&value[12..16])?,
+        month(&value[8..11])?,
+        digits(&value[5..7])?,
+        time(&value[17..25])?,
+    )
+}
+
+// Sunday, 06-Nov-94 08:49:37 GMT
+fn rfc850(value: &str, now: Date) -> Result<Date, WBDLError> {
+    let (name, rest) = value.split_once(", ").ok_or(WBDLError)?;
+    let bytes = rest.as_bytes();
+    if bytes.len() != 22 || bytes[2] != b'-' || bytes[6] != b'-' || bytes[9] != b' ' {
+        return Err(WBDLError);
+    }
+    if &rest[18..] != " GMT" {
+        return Err(WBDLError);
+    }
+    // a two digit year more than 50 years ahead belongs to the previous century
+    let mut year = now.year() as i32 / 100 * 100 + digits(&rest[7..9])? as i32;
+    if year > now.year() as i32 + 50 {
+        year -= 100;
+    }
+    build(
+        weekday(name, true)?,
+        u16::try_from(year).map_err(|_err| WBDLError)?,
+        month(&rest[3..6])?,
+        digits(&rest[..2])?,
+        time(&rest[10..18])?,
+    )
+}
+
+// Sun Nov  6 08:49:37 1994
+fn asctime(value: &str) -> Result<Date, WBDLError> {
+    let bytes = value.as_bytes();
+    if bytes.len() != 24 || bytes[3] != b' ' || bytes[7] != b' ' {
+        return Err(WBDLError);
+    }
+    if bytes[10] != b' ' || bytes[19] != b' ' {
+        return Err(WBDLError);
+    }
+    let day = value[8..10].strip_prefix(' ').unwrap_or(&value[8..10]);
+    build(
+        weekday(&value[..3], false)?,
+        digits(&value[20..])?,
+        month(&value[4..7])?,
+        digits(day)?,
+        time(&value[11..19])?,
+    )
+}
+
+impl Date {
+    // accepts IMF-fixdate, RFC 850 and asctime as RFC 9110 section 5.6.7 requires
+    pub fn parse_http_date(value: &str) -> Result<Date, WBDLError> {
+        Date::parse_http_date_relative_to(value, Date::now()?)
+    }
+    // resolves two digit RFC 850 years against now instead of the system clock
+    pub fn parse_http_date_relative_to(value: &str, now: Date) -> Result<Date, WBDLError> {
+        if !value.is_ascii() {
+            return Err(WBDLError);
+        }
+        imf_fixdate(value)
+            .or_else(|_err| rfc850(value, now))
+            .or_else(|_err| asctime(value))
+    }
+    // always IMF-fixdate
+    pub fn to_http_date(self) -> Result<String, WBDLError> {
+        if self.year() > 9999 {
+            return Err(WBDLError);
+        }
+        Ok(format!(
+            "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+            self.weekday().abbreviation(),
+            self.day(),
+            self.month().abbreviation(),
+            self.year(),
+            self.hour(),
+            self.minute(),
+            self.second()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use crate::test_util::date;
+    use crate::Date;
+
+    #[test]
+    pub fn parse_all_formats() {
+        let now = date("2023-12-24T12:12:12");
+        let expected = Ok(date("1994-11-06T08:49:37"));
+        for value in [
+            "Sun, 06 Nov 1994 08:49:37 GMT",
+            "Sunday, 06-Nov-94 08:49:37 GMT",
+            "Sun Nov  6 08:49:37 1994",
+        ] {
+            assert_eq!(Date::parse_http_date_relative_to(value, now), expected);
+        }
+        assert_eq!(
+            Date::parse_http_date_relative_to("Tuesday, 06-Nov-35 08:49:37 GMT", now),
+            Ok(date("2035-11-06T08:49:37"))
+        );
+        assert_eq!(
+            Date::parse_http_date_relative_to("Wed Nov 16 08:49:37 1994", now),
+            Ok(date("1994-11-16T08:49:37"))
+        );
+    }
+
+    #[test]
+    pub fn leap_second() {
+        let now = date("2023-12-24T12:12:12");
+        let leap = Date::parse_http_date_relative_to("Sat, 31 Dec 2016 23:59:60 GMT", now).unwrap();
+        let next = date("2017-01-01T00:00:00");
+        assert_eq!(leap, next);
+        assert_eq!(Ord::cmp(&leap, &next), std::cmp::Ordering::Equal);
+        assert_eq!(leap.second().value(), 0);
+    }
+
+    #[test]
+    pub fn reject_invalid() {
+        let now = date("2023-12-24T12:12:12");
+        for value in [
+            "Sun, 06 Nov 1994 08:49:37 UTC",
+            "sun, 06 Nov 1994 08:49:37 GMT",
+            "Sun, 6 Nov 1994 08:49:37 GMT",
+            "Mon, 06 Nov 1994 08:49:37 GMT",
+            "Sun, 06 NOV 1994 08:49:37 GMT",
+            "Sun, 06 Nov 1994 24:49:37 GMT",
+            "Sun, 06-Nov-94 08:49:37 GMT",
+            "Sun Nov 6 08:49:37 1994",
+        ] {
+            assert!(
+                Date::parse_http_date_relative_to(value, now).is_err(),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    pub fn two_digit_year_at_the_limits() {
+        let value = "Sunday, 06-Nov-94 08:49:37 GMT";
+        assert!(Date::parse_http_date_relative_to(value, date("0010-01-01T00:00:00")).is_err());
+        assert!(Date::parse_http_date_relative_to(value, date("65535-01-01T00:00:00")).is_err());
+        assert_eq!(
+            Date::parse_http_date_relative_to(
+                "Saturday, 06-Jan-01 08:49:37 GMT",
+                date("0010-01-01T00:00:00")
+            ),
+            Ok(date("0001-01-06T08:49:37"))
+        );
+    }
+
+    #[test]
+    pub fn system_time_round_trip() {
+        let value = date("2023-12-24T12:12:12");
+        assert_eq!(
+            value.to_http_date().unwrap(),
+            "Sun, 24 Dec 2023 12:12:12 GMT"
+        );
+        let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_703_419_932);
+        assert_eq!(SystemTime::from(value), modified);
+        let header = Date::try_from(modified).unwrap().to_http_date().unwrap();
+        assert_eq!(
+            Date::parse_http_date(&header).map(SystemTime::from),
+            Ok(modified)
+        );
+        assert_eq!(
+            SystemTime::from(date("1969-12-31T23:59:59")),
+            SystemTime::UNIX_EPOCH - Duration::from_secs(1)
+        );
+    }
+}
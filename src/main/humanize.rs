@@ -0,0 +1,242 @@
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::util::{SECONDS_PER_DAY, SECONDS_PER_HOUR, SECONDS_PER_MINUTE};
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct HumanizeOptions {
+    just_now: u32,
+    // the rounded count of a unit from which on the next bigger unit is used
+    thresholds: [u32; 5],
+    precise: bool,
+    max_units: u8,
+}
+
+impl Default for HumanizeOptions {
+    fn default() -> Self {
+        HumanizeOptions {
+            just_now: 10,
+            thresholds: [45, 45, 22, 26, 11],
+            precise: false,
+            max_units: 2,
+        }
+    }
+}
+
+impl TimeUnit {
+    pub const fn name(&self) -> &'static str {
+        match self {
+            TimeUnit::Second => "second",
+            TimeUnit::Minute => "minute",
+            TimeUnit::Hour => "hour",
+            TimeUnit::Day => "day",
+            TimeUnit::Month => "month",
+            TimeUnit::Year => "year",
+        }
+    }
+    fn describe(&self, count: i64) -> String {
+        let plural = if count == 1 { "" } else { "s" };
+        format!("{} {}{}", count, self.name(), plural)
+    }
+}
+
+impl HumanizeOptions {
+    pub fn new() -> HumanizeOptions {
+        HumanizeOptions::default()
+    }
+    // differences below this many seconds read "just now"
+    pub fn with_just_now(mut self, seconds: u32) -> HumanizeOptions {
+        self.just_now = seconds;
+        self
+    }
+    // years have no bigger unit and therefore no threshold
+    pub fn with_threshold(
+        mut self,
+        unit: TimeUnit,
+        value: u32,
+    ) -> Result<HumanizeOptions, WBDLError> {
+        if unit == TimeUnit::Year || value == 0 {
+            return Err(WBDLError);
+        }
+        self.thresholds[unit as usize] = value;
+        Ok(self)
+    }
+    pub fn with_precise(mut self, max_units: u8) -> Result<HumanizeOptions, WBDLError> {
+        if max_units == 0 {
+            return Err(WBDLError);
+        }
+        self.precise = true;
+        self.max_units = max_units;
+        Ok(self)
+    }
+    pub const fn just_now(&self) -> u32 {
+        self.just_now
+    }
+    pub fn threshold(&self, unit: TimeUnit) -> Option<u32> {
+        self.thresholds.get(unit as usize).copied()
+    }
+    pub const fn is_precise(&self) -> bool {
+        self.precise
+    }
+    pub const fn max_units(&self) -> u8 {
+        self.max_units
+    }
+}
+
+const fn rounded(value: i64, unit: i64) -> i64 {
+    (value + unit / 2) / unit
+}
+
+fn approximate(earlier: &Date, later: &Date, options: &HumanizeOptions) -> String {
+    let seconds = earlier.duration_until(later).as_seconds();
+    let [seconds_limit, minutes_limit, hours_limit, days_limit, months_limit] =
+        options.thresholds.map(|threshold| threshold as i64);
+    if seconds < seconds_limit {
+        return TimeUnit::Second.describe(seconds);
+    }
+    let minutes = rounded(seconds, SECONDS_PER_MINUTE as i64).max(1);
+    if minutes < minutes_limit {
+        return TimeUnit::Minute.describe(minutes);
+    }
+    let hours = rounded(seconds, SECONDS_PER_HOUR as i64).max(1);
+    if hours < hours_limit {
+        return TimeUnit::Hour.describe(hours);
+    }
+    let days = rounded(seconds, SECONDS_PER_DAY as i64).max(1);
+    if days < days_limit {
+        return TimeUnit::Day.describe(days);
+    }
+    // months follow the calendar, a remainder of half a month rounds up
+    let period = earlier.period_until(later);
+    let months = (period.total_months() + (period.days() >= 15) as i64).max(1);
+    if months < months_limit {
+        return TimeUnit::Month.describe(months);
+    }
+    TimeUnit::Year.describe(rounded(months, 12).max(1))
+}
+
+fn precise(earlier: &Date, later: &Date, options: &HumanizeOptions) -> String {
+    let period = earlier.period_until(later);
+    let rest = earlier
+        .add_period(period)
+        .map(|anchor| anchor.duration_until(later).as_seconds())
+        .unwrap_or(0);
+    let counts = [
+        (TimeUnit::Year, period.years() as i64),
+        (TimeUnit::Month, period.months() as i64),
+        (TimeUnit::Day, period.days() as i64),
+        (TimeUnit::Hour, rest / SECONDS_PER_HOUR as i64),
+        (
+            TimeUnit::Minute,
+            rest % SECONDS_PER_HOUR as i64 / SECONDS_PER_MINUTE as i64,
+        ),
+        (TimeUnit::Second, rest % SECONDS_PER_MINUTE as i64),
+    ];
+    counts
+        .iter()
+        .filter(|(_, count)| *count != 0)
+        .take(options.max_units as usize)
+        .map(|(unit, count)| unit.describe(*count))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+impl Date {
+    // describes self from the point of view of reference, e.g. "3 hours ago" or "in 2 days"
+    pub fn humanize_relative_to(&self, reference: &Date) -> String {
+        self.humanize_relative_to_with(reference, &HumanizeOptions::default())
+    }
+    pub fn humanize_relative_to_with(&self, reference: &Date, options: &HumanizeOptions) -> String {
+        let (earlier, later) = if self < reference {
+            (self, reference)
+        } else {
+            (reference, self)
+        };
+        let seconds = earlier.duration_until(later).as_seconds();
+        if seconds == 0 || seconds < options.just_now as i64 {
+            return String::from("just now");
+        }
+        let text = if options.precise {
+            precise(earlier, later, options)
+        } else {
+            approximate(earlier, later, options)
+        };
+        if self < reference {
+            format!("{} ago", text)
+        } else {
+            format!("in {}", text)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::{HumanizeOptions, TimeUnit};
+
+    #[test]
+    pub fn approximate() {
+        let now = date("2023-12-24T12:00:00");
+        let cases = [
+            ("2023-12-24T11:59:55", "just now"),
+            ("2023-12-24T11:59:30", "30 seconds ago"),
+            ("2023-12-24T11:59:00", "1 minute ago"),
+            ("2023-12-24T09:05:00", "3 hours ago"),
+            ("2023-12-26T11:00:00", "in 2 days"),
+            ("2023-10-20T12:00:00", "2 months ago"),
+            ("2025-02-01T12:00:00", "in 1 year"),
+            ("2020-12-24T12:00:00", "3 years ago"),
+        ];
+        for (other, expected) in cases {
+            assert_eq!(date(other).humanize_relative_to(&now), expected);
+        }
+    }
+
+    #[test]
+    pub fn thresholds() {
+        let now = date("2023-12-24T12:00:00");
+        let options = HumanizeOptions::new()
+            .with_just_now(0)
+            .with_threshold(TimeUnit::Hour, 48)
+            .unwrap();
+        assert_eq!(
+            date("2023-12-23T00:00:00").humanize_relative_to_with(&now, &options),
+            "36 hours ago"
+        );
+        assert_eq!(
+            date("2023-12-24T12:00:03").humanize_relative_to_with(&now, &options),
+            "in 3 seconds"
+        );
+        assert!(HumanizeOptions::new()
+            .with_threshold(TimeUnit::Year, 2)
+            .is_err());
+    }
+
+    #[test]
+    pub fn precise() {
+        let now = date("2023-12-24T12:00:00");
+        let options = HumanizeOptions::new().with_precise(2).unwrap();
+        assert_eq!(
+            date("2023-12-22T09:00:00").humanize_relative_to_with(&now, &options),
+            "2 days, 3 hours ago"
+        );
+        assert_eq!(
+            date("2025-01-24T12:00:01").humanize_relative_to_with(&now, &options),
+            "in 1 year, 1 month"
+        );
+        let all = HumanizeOptions::new().with_precise(6).unwrap();
+        assert_eq!(
+            date("2023-12-24T13:01:01").humanize_relative_to_with(&now, &all),
+            "in 1 hour, 1 minute, 1 second"
+        );
+    }
+}
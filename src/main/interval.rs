@@ -0,0 +1,352 @@
+use std::cmp::{max, min};
+
+use crate::date::Date;
+use crate::duration::Duration;
+use crate::error::WBDLError;
+use crate::range::DateRange;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    start: Date,
+    end: Date,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl Interval {
+    pub fn new(start: Date, end: Date) -> Result<Interval, WBDLError> {
+        if start <= end {
+            Ok(Interval { start, end })
+        } else {
+            Err(WBDLError)
+        }
+    }
+    pub const fn start(&self) -> Date {
+        self.start
+    }
+    pub const fn end(&self) -> Date {
+        self.end
+    }
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+    pub fn duration(&self) -> Duration {
+        self.start.duration_until(&self.end)
+    }
+    pub fn contains(&self, date: &Date) -> bool {
+        &self.start <= date && date < &self.end
+    }
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+    pub fn touches(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        if self.overlaps(other) {
+            Some(Interval {
+                start: max(self.start, other.start),
+                end: min(self.end, other.end),
+            })
+        } else {
+            None
+        }
+    }
+    // only defined when the result is a single interval
+    pub fn union(&self, other: &Interval) -> Option<Interval> {
+        if self.touches(other) {
+            Some(self.span(other))
+        } else {
+            None
+        }
+    }
+    pub fn span(&self, other: &Interval) -> Interval {
+        Interval {
+            start: min(self.start, other.start),
+            end: max(self.end, other.end),
+        }
+    }
+    pub fn difference(&self, other: &Interval) -> Vec<Interval> {
+        if !self.overlaps(other) {
+            return if self.is_empty() { vec![] } else { vec![*self] };
+        }
+        let mut parts = Vec::with_capacity(2);
+        if self.start < other.start {
+            parts.push(Interval {
+                start: self.start,
+                end: other.start,
+            });
+        }
+        if other.end < self.end {
+            parts.push(Interval {
+                start: other.end,
+                end: self.end,
+            });
+        }
+        parts
+    }
+    pub fn gap(&self, other: &Interval) -> Option<Interval> {
+        if self.end < other.start {
+            Some(Interval {
+                start: self.end,
+                end: other.start,
+            })
+        } else if other.end < self.start {
+            Some(Interval {
+                start: other.end,
+                end: self.start,
+            })
+        } else {
+            None
+        }
+    }
+    pub const fn range(&self) -> DateRange {
+        DateRange::new(self.start, self.end)
+    }
+}
+
+impl From<Interval> for DateRange {
+    fn from(value: Interval) -> Self {
+        value.range()
+    }
+}
+
+impl IntervalSet {
+    pub const fn new() -> IntervalSet {
+        IntervalSet { intervals: vec![] }
+    }
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+    pub fn iter(&self) -> std::slice::Iter<'_, Interval> {
+        self.intervals.iter()
+    }
+    pub fn as_slice(&self) -> &[Interval] {
+        &self.intervals
+    }
+    pub fn span(&self) -> Option<Interval> {
+        Some(Interval {
+            start: self.intervals.first()?.start,
+            end: self.intervals.last()?.end,
+        })
+    }
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self
+            .intervals
+            .partition_point(|current| current.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|current| current.start <= interval.end);
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, current| merged.span(current));
+        self.intervals.splice(first..last, [merged]);
+    }
+    pub fn remove(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self
+            .intervals
+            .partition_point(|current| current.end <= interval.start);
+        let last = self
+            .intervals
+            .partition_point(|current| current.start < interval.end);
+        let remaining: Vec<Interval> = self.intervals[first..last]
+            .iter()
+            .flat_map(|current| current.difference(&interval))
+            .collect();
+        self.intervals.splice(first..last, remaining);
+    }
+    pub fn contains(&self, date: &Date) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|current| &current.end <= date);
+        self.intervals
+            .get(index)
+            .map(|current| current.contains(date))
+            .unwrap_or(false)
+    }
+    pub fn contains_interval(&self, interval: &Interval) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|current| current.end < interval.end);
+        interval.is_empty()
+            || self
+                .intervals
+                .get(index)
+                .map(|current| current.contains_interval(interval))
+                .unwrap_or(false)
+    }
+    pub fn overlaps(&self, interval: &Interval) -> bool {
+        self.intervals
+            .iter()
+            .any(|current| current.overlaps(interval))
+    }
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        other
+            .intervals
+            .iter()
+            .for_each(|interval| union.insert(*interval));
+        union
+    }
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = vec![];
+        let (mut left, mut right) = (0, 0);
+        while left < self.intervals.len() && right < other.intervals.len() {
+            let (first, second) = (&self.intervals[left], &other.intervals[right]);
+            if let Some(intersection) = first.intersection(second) {
+                intervals.push(intersection);
+            }
+            if first.end <= second.end {
+                left += 1;
+            } else {
+                right += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut difference = self.clone();
+        other
+            .intervals
+            .iter()
+            .for_each(|interval| difference.remove(*interval));
+        difference
+    }
+    pub fn gaps(&self) -> IntervalSet {
+        IntervalSet {
+            intervals: self
+                .intervals
+                .windows(2)
+                .filter_map(|pair| pair[0].gap(&pair[1]))
+                .collect(),
+        }
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(value: Interval) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(value);
+        set
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        let mut set = IntervalSet::new();
+        iter.into_iter().for_each(|interval| set.insert(interval));
+        set
+    }
+}
+
+impl Extend<Interval> for IntervalSet {
+    fn extend<T: IntoIterator<Item = Interval>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|interval| self.insert(interval));
+    }
+}
+
+impl IntoIterator for IntervalSet {
+    type Item = Interval;
+    type IntoIter = std::vec::IntoIter<Interval>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a IntervalSet {
+    type Item = &'a Interval;
+    type IntoIter = std::slice::Iter<'a, Interval>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, Interval, IntervalSet};
+
+    fn interval(start: u8, end: u8) -> Interval {
+        let day = |hour: u8| Date::new(2024, crate::Month::March, 1, hour, 0, 0).unwrap();
+        Interval::new(day(start), day(end)).unwrap()
+    }
+
+    #[test]
+    pub fn rejects_reversed() {
+        assert!(Interval::new(interval(2, 3).end(), interval(2, 3).start()).is_err())
+    }
+
+    #[test]
+    pub fn overlap_and_intersection() {
+        assert!(interval(1, 4).overlaps(&interval(3, 6)));
+        assert!(!interval(1, 3).overlaps(&interval(3, 6)));
+        assert_eq!(
+            interval(1, 4).intersection(&interval(3, 6)),
+            Some(interval(3, 4))
+        );
+        assert_eq!(interval(1, 3).intersection(&interval(3, 6)), None);
+    }
+
+    #[test]
+    pub fn union_difference_and_gap() {
+        assert_eq!(interval(1, 3).union(&interval(3, 6)), Some(interval(1, 6)));
+        assert_eq!(interval(1, 2).union(&interval(3, 6)), None);
+        assert_eq!(
+            interval(1, 8).difference(&interval(3, 6)),
+            vec![interval(1, 3), interval(6, 8)]
+        );
+        assert_eq!(interval(1, 4).difference(&interval(0, 9)), vec![]);
+        assert_eq!(interval(1, 2).gap(&interval(5, 6)), Some(interval(2, 5)));
+        assert_eq!(interval(5, 6).gap(&interval(1, 2)), Some(interval(2, 5)));
+        assert_eq!(interval(1, 2).gap(&interval(2, 6)), None);
+    }
+
+    #[test]
+    pub fn set_stays_normalized() {
+        let set: IntervalSet = vec![
+            interval(8, 10),
+            interval(1, 2),
+            interval(2, 4),
+            interval(12, 14),
+            interval(9, 13),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.as_slice(), &[interval(1, 4), interval(8, 14)]);
+        assert_eq!(set.gaps().as_slice(), &[interval(4, 8)]);
+        assert!(set.contains(&interval(3, 4).start()));
+        assert!(!set.contains(&interval(4, 5).start()));
+        assert!(set.contains_interval(&interval(9, 14)));
+    }
+
+    #[test]
+    pub fn set_operations() {
+        let first: IntervalSet = vec![interval(1, 5), interval(8, 12)].into_iter().collect();
+        let second: IntervalSet = vec![interval(4, 9), interval(11, 15)].into_iter().collect();
+        assert_eq!(first.union(&second).as_slice(), &[interval(1, 15)]);
+        assert_eq!(
+            first.intersection(&second).as_slice(),
+            &[interval(4, 5), interval(8, 9), interval(11, 12)]
+        );
+        assert_eq!(
+            first.difference(&second).as_slice(),
+            &[interval(1, 4), interval(9, 11)]
+        );
+    }
+}
@@ -0,0 +1,545 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::date::Date;
+use crate::duration::Duration;
+use crate::error::WBDLError;
+use crate::interval::Interval;
+use crate::period::Period;
+use crate::util::{SECONDS_PER_HOUR, SECONDS_PER_MINUTE};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IsoDuration {
+    years: u32,
+    months: u32,
+    weeks: u32,
+    days: u32,
+    hours: u32,
+    minutes: u32,
+    seconds: u32,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IsoInterval {
+    StartEnd(Date, Date),
+    StartDuration(Date, IsoDuration),
+    DurationEnd(IsoDuration, Date),
+    Duration(IsoDuration),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Recurrence {
+    repetitions: Option<u32>,
+    interval: IsoInterval,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecurrenceIter {
+    recurrence: Recurrence,
+    index: u32,
+}
+
+impl IsoDuration {
+    pub const ZERO: IsoDuration = IsoDuration::new(0, 0, 0, 0, 0, 0);
+    pub const fn new(
+        years: u32,
+        months: u32,
+        days: u32,
+        hours: u32,
+        minutes: u32,
+        seconds: u32,
+    ) -> IsoDuration {
+        IsoDuration {
+            years,
+            months,
+            weeks: 0,
+            days,
+            hours,
+            minutes,
+            seconds,
+        }
+    }
+    pub const fn from_weeks(weeks: u32) -> IsoDuration {
+        IsoDuration {
+            weeks,
+            ..IsoDuration::ZERO
+        }
+    }
+    pub const fn years(&self) -> u32 {
+        self.years
+    }
+    pub const fn months(&self) -> u32 {
+        self.months
+    }
+    pub const fn weeks(&self) -> u32 {
+        self.weeks
+    }
+    pub const fn days(&self) -> u32 {
+        self.days
+    }
+    pub const fn hours(&self) -> u32 {
+        self.hours
+    }
+    pub const fn minutes(&self) -> u32 {
+        self.minutes
+    }
+    pub const fn seconds(&self) -> u32 {
+        self.seconds
+    }
+    pub const fn is_zero(&self) -> bool {
+        self.years == 0
+            && self.months == 0
+            && self.weeks == 0
+            && self.days == 0
+            && self.hours == 0
+            && self.minutes == 0
+            && self.seconds == 0
+    }
+    pub fn checked_mul(&self, factor: u32) -> Option<IsoDuration> {
+        Some(IsoDuration {
+            years: self.years.checked_mul(factor)?,
+            months: self.months.checked_mul(factor)?,
+            weeks: self.weeks.checked_mul(factor)?,
+            days: self.days.checked_mul(factor)?,
+            hours: self.hours.checked_mul(factor)?,
+            minutes: self.minutes.checked_mul(factor)?,
+            seconds: self.seconds.checked_mul(factor)?,
+        })
+    }
+    // fails when a component does not fit into a Period
+    pub fn period(&self) -> Result<Period, WBDLError> {
+        let convert = |value: u32| i32::try_from(value).map_err(|_err| WBDLError);
+        let days = self
+            .weeks
+            .checked_mul(7)
+            .and_then(|days| days.checked_add(self.days))
+            .ok_or(WBDLError)?;
+        Ok(Period::new(
+            convert(self.years)?,
+            convert(self.months)?,
+            convert(days)?,
+        ))
+    }
+    pub const fn duration(&self) -> Duration {
+        Duration::from_seconds(self.exact_seconds())
+    }
+    // calendar parts first, then the exact parts, as ISO-8601 prescribes
+    pub fn add_to(&self, date: Date) -> Result<Date, WBDLError> {
+        date.add_period(self.period()?)?
+            .add_duration(self.duration())
+    }
+    pub fn subtract_from(&self, date: Date) -> Result<Date, WBDLError> {
+        let period = self.period()?;
+        date.add_duration(-self.duration())?
+            .add_days(-(period.days() as i64))?
+            .add_months(-period.total_months())
+    }
+    const fn exact_seconds(&self) -> i64 {
+        self.hours as i64 * SECONDS_PER_HOUR as i64
+            + self.minutes as i64 * SECONDS_PER_MINUTE as i64
+            + self.seconds as i64
+    }
+}
+
+impl IsoInterval {
+    pub fn start(&self) -> Result<Date, WBDLError> {
+        match self {
+            IsoInterval::StartEnd(start, _) | IsoInterval::StartDuration(start, _) => Ok(*start),
+            IsoInterval::DurationEnd(duration, end) => duration.subtract_from(*end),
+            IsoInterval::Duration(_) => Err(WBDLError),
+        }
+    }
+    pub fn end(&self) -> Result<Date, WBDLError> {
+        match self {
+            IsoInterval::StartEnd(_, end) | IsoInterval::DurationEnd(_, end) => Ok(*end),
+            IsoInterval::StartDuration(start, duration) => duration.add_to(*start),
+            IsoInterval::Duration(_) => Err(WBDLError),
+        }
+    }
+    pub fn to_interval(&self) -> Result<Interval, WBDLError> {
+        Interval::new(self.start()?, self.end()?)
+    }
+}
+
+impl TryFrom<IsoInterval> for Interval {
+    type Error = WBDLError;
+    fn try_from(value: IsoInterval) -> Result<Self, Self::Error> {
+        value.to_interval()
+    }
+}
+
+impl From<Interval> for IsoInterval {
+    fn from(value: Interval) -> Self {
+        IsoInterval::StartEnd(value.start(), value.end())
+    }
+}
+
+impl Recurrence {
+    pub const fn new(repetitions: Option<u32>, interval: IsoInterval) -> Recurrence {
+        Recurrence {
+            repetitions,
+            interval,
+        }
+    }
+    pub const fn repetitions(&self) -> Option<u32> {
+        self.repetitions
+    }
+    pub const fn interval(&self) -> IsoInterval {
+        self.interval
+    }
+    pub const fn iter(&self) -> RecurrenceIter {
+        RecurrenceIter {
+            recurrence: *self,
+            index: 0,
+        }
+    }
+    // occurrence `index`, counted backwards from the end for `duration/end` recurrences
+    pub fn get(&self, index: u32) -> Result<Interval, WBDLError> {
+        if self
+            .repetitions
+            .is_some_and(|repetitions| index >= repetitions)
+        {
+            return Err(WBDLError);
+        }
+        match self.interval {
+            IsoInterval::StartEnd(start, end) => {
+                let length = end.timestamp() - start.timestamp();
+                let start =
+                    start.add_seconds(length.checked_mul(index as i64).ok_or(WBDLError)?)?;
+                Interval::new(start, start.add_seconds(length)?)
+            }
+            IsoInterval::StartDuration(start, duration) => Interval::new(
+                duration
+                    .checked_mul(index)
+                    .ok_or(WBDLError)?
+                    .add_to(start)?,
+                duration
+                    .checked_mul(index + 1)
+                    .ok_or(WBDLError)?
+                    .add_to(start)?,
+            ),
+            IsoInterval::DurationEnd(duration, end) => Interval::new(
+                duration
+                    .checked_mul(index + 1)
+                    .ok_or(WBDLError)?
+                    .subtract_from(end)?,
+                duration
+                    .checked_mul(index)
+                    .ok_or(WBDLError)?
+                    .subtract_from(end)?,
+            ),
+            IsoInterval::Duration(_) => Err(WBDLError),
+        }
+    }
+}
+
+impl IntoIterator for Recurrence {
+    type Item = Interval;
+    type IntoIter = RecurrenceIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Iterator for RecurrenceIter {
+    type Item = Interval;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let interval = self.recurrence.get(self.index).ok()?;
+        self.index = self.index.checked_add(1)?;
+        Some(interval)
+    }
+}
+
+fn parse_date(value: &str) -> Result<Date, WBDLError> {
+    Date::try_from(value.strip_suffix('Z').unwrap_or(value))
+}
+
+fn parse_components(
+    mut value: &str,
+    designators: &[char],
+    target: &mut [&mut u32],
+) -> Result<(), WBDLError> {
+    let mut next = 0;
+    while !value.is_empty() {
+        let digits = value
+            .find(|char: char| !char.is_ascii_digit())
+            .ok_or(WBDLError)?;
+        let designator = value[digits..].chars().next().ok_or(WBDLError)?;
+        let index = designators[next..]
+            .iter()
+            .position(|current| current == &designator)
+            .ok_or(WBDLError)?
+            + next;
+        if digits == 0 {
+            return Err(WBDLError);
+        }
+        *target[index] = u32::from_str(&value[..digits]).map_err(|_err| WBDLError)?;
+        next = index + 1;
+        value = &value[digits + designator.len_utf8()..];
+    }
+    Ok(())
+}
+
+impl TryFrom<&str> for IsoDuration {
+    type Error = WBDLError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.strip_prefix('P').ok_or(WBDLError)?;
+        let (date, time) = match value.split_once('T') {
+            Some((_, "")) => return Err(WBDLError),
+            Some((date, time)) => (date, time),
+            None => (value, ""),
+        };
+        if date.is_empty() && time.is_empty() {
+            return Err(WBDLError);
+        }
+        let mut duration = IsoDuration::ZERO;
+        parse_components(
+            date,
+            &['Y', 'M', 'W', 'D'],
+            &mut [
+                &mut duration.years,
+                &mut duration.months,
+                &mut duration.weeks,
+                &mut duration.days,
+            ],
+        )?;
+        parse_components(
+            time,
+            &['H', 'M', 'S'],
+            &mut [
+                &mut duration.hours,
+                &mut duration.minutes,
+                &mut duration.seconds,
+            ],
+        )?;
+        Ok(duration)
+    }
+}
+
+impl TryFrom<&str> for IsoInterval {
+    type Error = WBDLError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.split_once('/') {
+            None => Ok(IsoInterval::Duration(IsoDuration::try_from(value)?)),
+            Some((start, end)) if start.starts_with('P') => Ok(IsoInterval::DurationEnd(
+                IsoDuration::try_from(start)?,
+                parse_date(end)?,
+            )),
+            Some((start, end)) if end.starts_with('P') => Ok(IsoInterval::StartDuration(
+                parse_date(start)?,
+                IsoDuration::try_from(end)?,
+            )),
+            Some((start, end)) => {
+                let (start, end) = (parse_date(start)?, parse_date(end)?);
+                if start <= end {
+                    Ok(IsoInterval::StartEnd(start, end))
+                } else {
+                    Err(WBDLError)
+                }
+            }
+        }
+    }
+}
+
+impl TryFrom<&str> for Recurrence {
+    type Error = WBDLError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (repetitions, interval) = value
+            .strip_prefix('R')
+            .and_then(|value| value.split_once('/'))
+            .ok_or(WBDLError)?;
+        let repetitions = if repetitions.is_empty() {
+            None
+        } else if repetitions.bytes().all(|byte| byte.is_ascii_digit()) {
+            Some(u32::from_str(repetitions).map_err(|_err| WBDLError)?)
+        } else {
+            return Err(WBDLError);
+        };
+        Ok(Recurrence {
+            repetitions,
+            interval: IsoInterval::try_from(interval)?,
+        })
+    }
+}
+
+impl FromStr for IsoDuration {
+    type Err = WBDLError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl FromStr for IsoInterval {
+    type Err = WBDLError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl FromStr for Recurrence {
+    type Err = WBDLError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl Display for IsoDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "PT0S");
+        }
+        write!(f, "P")?;
+        for (value, designator) in [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if value != 0 {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+        if self.exact_seconds() != 0 {
+            write!(f, "T")?;
+        }
+        for (value, designator) in [(self.hours, 'H'), (self.minutes, 'M'), (self.seconds, 'S')] {
+            if value != 0 {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// the zero padded extended format, Display of Date itself does not pad
+pub(crate) struct IsoDate<'a>(pub(crate) &'a Date);
+
+impl Display for IsoDate<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(&self.0.format("%FT%T").map_err(|_err| std::fmt::Error)?)
+    }
+}
+
+impl Display for IsoInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IsoInterval::StartEnd(start, end) => {
+                write!(f, "{}/{}", IsoDate(start), IsoDate(end))
+            }
+            IsoInterval::StartDuration(start, duration) => {
+                write!(f, "{}/{}", IsoDate(start), duration)
+            }
+            IsoInterval::DurationEnd(duration, end) => write!(f, "{}/{}", duration, IsoDate(end)),
+            IsoInterval::Duration(duration) => Display::fmt(duration, f),
+        }
+    }
+}
+
+impl Display for Recurrence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.repetitions {
+            Some(repetitions) => write!(f, "R{}/{}", repetitions, self.interval),
+            None => write!(f, "R/{}", self.interval),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::{Date, Interval, IsoDuration, IsoInterval, Recurrence};
+
+    #[test]
+    pub fn parse_duration() {
+        let duration = IsoDuration::try_from("P1Y2M10DT2H30M").unwrap();
+        assert_eq!(duration, IsoDuration::new(1, 2, 10, 2, 30, 0));
+        assert_eq!(duration.to_string(), "P1Y2M10DT2H30M");
+        assert_eq!(
+            IsoDuration::try_from("P3W").map(|duration| duration.weeks()),
+            Ok(3)
+        );
+        assert_eq!(IsoDuration::ZERO.to_string(), "PT0S");
+    }
+
+    #[test]
+    pub fn reject_malformed_duration() {
+        for value in ["P", "PT", "P1H", "P1M1Y", "PT1.5S", "1Y", "P1YT", "PYD"] {
+            assert!(IsoDuration::try_from(value).is_err(), "{}", value);
+        }
+    }
+
+    #[test]
+    pub fn duration_is_calendar_aware() {
+        let duration = IsoDuration::try_from("P1M1DT1H").unwrap();
+        assert_eq!(
+            duration.add_to(date("2023-01-31T23:00:00")),
+            Ok(date("2023-03-02T00:00:00"))
+        );
+        assert_eq!(
+            duration.subtract_from(date("2023-03-02T00:00:00")),
+            Ok(date("2023-01-28T23:00:00"))
+        );
+    }
+
+    #[test]
+    pub fn huge_components_are_errors() {
+        let start = date("2023-01-01T00:00:00");
+        for value in [
+            "P1000000000W",
+            "P3000000000Y",
+            "P3000000000M",
+            "P3000000000D",
+        ] {
+            let duration = IsoDuration::try_from(value).unwrap();
+            assert!(duration.period().is_err(), "{}", value);
+            assert!(duration.add_to(start).is_err(), "{}", value);
+            assert!(duration.subtract_from(start).is_err(), "{}", value);
+        }
+        let interval = IsoInterval::try_from("2023-01-01T00:00:00/P1000000000W").unwrap();
+        assert!(interval.end().is_err());
+        assert!(IsoDuration::try_from("P2Y3W1D").unwrap().period().is_ok());
+    }
+
+    #[test]
+    pub fn parse_interval() {
+        let interval = IsoInterval::try_from("2023-01-01T00:00:00/P1M").unwrap();
+        assert_eq!(interval.end(), Ok(date("2023-02-01T00:00:00")));
+        assert_eq!(
+            IsoInterval::try_from("2023-01-01T00:00:00/2023-02-01T00:00:00")
+                .and_then(Interval::try_from),
+            interval.to_interval()
+        );
+        assert_eq!(
+            IsoInterval::try_from("P1D/2023-01-01T00:00:00Z").and_then(|value| value.start()),
+            Ok(date("2022-12-31T00:00:00"))
+        );
+        assert_eq!(interval.to_string(), "2023-01-01T00:00:00/P1M");
+        assert!(IsoInterval::try_from("2023-02-01T00:00:00/2023-01-01T00:00:00").is_err());
+    }
+
+    #[test]
+    pub fn recurrence() {
+        let recurrence = Recurrence::try_from("R5/2023-01-31T00:00:00/P1M").unwrap();
+        let starts: Vec<Date> = recurrence.iter().map(|value| value.start()).collect();
+        assert_eq!(starts.len(), 5);
+        assert_eq!(starts[1], date("2023-02-28T00:00:00"));
+        assert_eq!(starts[2], date("2023-03-31T00:00:00"));
+        assert_eq!(recurrence.to_string(), "R5/2023-01-31T00:00:00/P1M");
+        let unbounded = Recurrence::try_from("R/2023-01-01T00:00:00/PT1H").unwrap();
+        assert_eq!(unbounded.repetitions(), None);
+        assert_eq!(
+            unbounded.iter().nth(25).map(|value| value.end()),
+            Some(date("2023-01-02T02:00:00"))
+        );
+        assert_eq!(
+            Recurrence::try_from("R2/PT1H/2023-01-01T00:00:00")
+                .unwrap()
+                .iter()
+                .last()
+                .map(|value| value.start()),
+            Some(date("2022-12-31T22:00:00"))
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::month::Month;
+use crate::util::{
+    days_from_civil, days_from_julian, is_julian_leap_year, julian_from_days, SECONDS_PER_DAY,
+};
+
+// a calendar date in the proleptic julian calendar
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct JulianDate {
+    year: u16,
+    month: Month,
+    day: u8,
+}
+
+// what to do with the dates that were skipped when the gregorian calendar was adopted
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum SwitchoverPolicy {
+    #[default]
+    Reject,
+    // keep counting in the julian calendar, 1582-10-10 becomes 1582-10-20
+    Map,
+}
+
+// julian before the switchover, gregorian from it on
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct HistoricalCalendar {
+    switchover: Date,
+    policy: SwitchoverPolicy,
+}
+
+fn date_from_days(days: i64) -> Result<Date, WBDLError> {
+    Date::from_timestamp(days * SECONDS_PER_DAY as i64)
+}
+
+impl JulianDate {
+    pub fn new(year: u16, month: Month, day: u8) -> Result<JulianDate, WBDLError> {
+        let max = match month {
+            Month::February if is_julian_leap_year(year) => 29,
+            Month::February => 28,
+            Month::April | Month::June | Month::September | Month::November => 30,
+            _ => 31,
+        };
+        if day == 0 || day > max {
+            return Err(WBDLError);
+        }
+        Ok(JulianDate { year, month, day })
+    }
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+    pub const fn month(&self) -> Month {
+        self.month
+    }
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+    pub const fn is_leap_year(&self) -> bool {
+        is_julian_leap_year(self.year)
+    }
+    // midnight of the same day in the gregorian calendar, fails for the first two days of year 0
+    pub fn to_date(self) -> Result<Date, WBDLError> {
+        date_from_days(days_from_julian(self.year, self.month as u8, self.day))
+    }
+}
+
+impl TryFrom<Date> for JulianDate {
+    type Error = WBDLError;
+    fn try_from(value: Date) -> Result<Self, Self::Error> {
+        let days = days_from_civil(value.year(), value.month() as u8, value.day().value());
+        let [year, month, day] = julian_from_days(days);
+        JulianDate::new(
+            u16::try_from(year).map_err(|_err| WBDLError)?,
+            Month::try_from(month as usize)?,
+            day as u8,
+        )
+    }
+}
+
+impl Date {
+    pub fn to_julian(self) -> Result<JulianDate, WBDLError> {
+        JulianDate::try_from(self)
+    }
+}
+
+// yyyy-mm-dd
+impl TryFrom<&str> for JulianDate {
+    type Error = WBDLError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut parts = value.split('-');
+        let mut next = || {
+            parts
+                .next()
+                .filter(|part| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit()))
+                .ok_or(WBDLError)
+        };
+        let year = u16::from_str(next()?).map_err(|_err| WBDLError)?;
+        let month = Month::try_from(usize::from_str(next()?).map_err(|_err| WBDLError)?)?;
+        let day = u8::from_str(next()?).map_err(|_err| WBDLError)?;
+        if parts.next().is_some() {
+            return Err(WBDLError);
+        }
+        JulianDate::new(year, month, day)
+    }
+}
+
+impl FromStr for JulianDate {
+    type Err = WBDLError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        JulianDate::try_from(s)
+    }
+}
+
+impl Display for JulianDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(&format!(
+            "{:04}-{:02}-{:02}",
+            self.year,
+            self.month.ordinal(),
+            self.day
+        ))
+    }
+}
+
+impl Default for HistoricalCalendar {
+    // the papal bull of 1582, thursday 4 october was followed by friday 15 october
+    fn default() -> Self {
+        HistoricalCalendar {
+            switchover: date_from_days(days_from_civil(1582, 10, 15))
+                .expect("1582-10-15 is a valid date"),
+            policy: SwitchoverPolicy::default(),
+        }
+    }
+}
+
+impl HistoricalCalendar {
+    // switchover is the first gregorian day, e.g. 1752-09-14 for great britain
+    pub fn new(switchover: Date) -> HistoricalCalendar {
+        HistoricalCalendar {
+            switchover: switchover.reset_until_hours(),
+            policy: SwitchoverPolicy::default(),
+        }
+    }
+    pub fn with_policy(mut self, policy: SwitchoverPolicy) -> HistoricalCalendar {
+        self.policy = policy;
+        self
+    }
+    pub const fn switchover(&self) -> Date {
+        self.switchover
+    }
+    pub const fn policy(&self) -> SwitchoverPolicy {
+        self.policy
+    }
+    // midnight of the day with that label in this calendar
+    pub fn date(&self, year: u16, month: Month, day: u8) -> Result<Date, WBDLError> {
+        let switchover = (
+            self.switchover.year(),
+            self.switchover.month(),
+            self.switchover.day().value(),
+        );
+        if (year, month, day) >= switchover {
+            return Date::new(year, month, day, 0, 0, 0);
+        }
+        let date = JulianDate::new(year, month, day)?.to_date()?;
+        if date >= self.switchover && self.policy == SwitchoverPolicy::Reject {
+            return Err(WBDLError);
+        }
+        Ok(date)
+    }
+    // the label of the day in this calendar, julian before the switchover
+    pub fn year_month_day(&self, date: &Date) -> Result<(u16, Month, u8), WBDLError> {
+        if date >= &self.switchover {
+            return Ok((date.year(), date.month(), date.day().value()));
+        }
+        let julian = date.to_julian()?;
+        Ok((julian.year(), julian.month(), julian.day()))
+    }
+    pub fn is_leap_year(&self, year: u16) -> bool {
+        self.with_policy(SwitchoverPolicy::Reject)
+            .date(year, Month::February, 29)
+            .is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::{HistoricalCalendar, JulianDate, Month, SwitchoverPolicy};
+
+    #[test]
+    pub fn julian_dates() {
+        let julian = JulianDate::new(1582, Month::October, 4).unwrap();
+        assert_eq!(julian.to_date(), Ok(date("1582-10-14T00:00:00")));
+        assert_eq!(
+            date("2024-01-14T18:00:00").to_julian(),
+            "2024-01-01".parse()
+        );
+        assert_eq!(
+            JulianDate::try_from("1900-02-29").unwrap().to_string(),
+            "1900-02-29"
+        );
+        assert!(JulianDate::new(1901, Month::February, 29).is_err());
+        assert!(JulianDate::try_from("1900-2").is_err());
+        assert!(JulianDate::try_from("1900-02-+1").is_err());
+        assert_eq!(
+            date("0000-01-01T00:00:00").to_julian(),
+            "0000-01-03".parse()
+        );
+        assert!(JulianDate::new(0, Month::January, 2)
+            .unwrap()
+            .to_date()
+            .is_err());
+    }
+
+    #[test]
+    pub fn switchover() {
+        let calendar = HistoricalCalendar::default();
+        assert_eq!(
+            calendar.date(1582, Month::October, 4).unwrap().add_days(1),
+            calendar.date(1582, Month::October, 15)
+        );
+        assert!(calendar.date(1582, Month::October, 10).is_err());
+        let mapped = calendar.with_policy(SwitchoverPolicy::Map);
+        assert_eq!(
+            mapped.date(1582, Month::October, 10),
+            Ok(date("1582-10-20T00:00:00"))
+        );
+        assert_eq!(
+            calendar.year_month_day(&date("1582-10-14T12:00:00")),
+            Ok((1582, Month::October, 4))
+        );
+        assert!(calendar.is_leap_year(1500));
+        assert!(!calendar.is_leap_year(1700));
+        let british = HistoricalCalendar::new(date("1752-09-14T00:00:00"));
+        assert!(british.is_leap_year(1700));
+        assert_eq!(
+            british.date(1752, Month::September, 2).unwrap().add_days(1),
+            british.date(1752, Month::September, 14)
+        );
+    }
+}
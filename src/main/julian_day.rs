@@ -0,0 +1,82 @@
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::util::{MODIFIED_JULIAN_DATE_OFFSET, SECONDS_PER_DAY, UNIX_EPOCH_JULIAN_DAY};
+
+// julian date of 1970-01-01T00:00:00
+const UNIX_EPOCH_JULIAN_DATE: f64 = UNIX_EPOCH_JULIAN_DAY as f64 - 0.5;
+
+// rounds to the nearest second
+fn from_days_since_epoch(days: f64) -> Result<Date, WBDLError> {
+    let seconds = (days * SECONDS_PER_DAY as f64).round();
+    if !seconds.is_finite() || seconds.abs() >= i64::MAX as f64 {
+        return Err(WBDLError);
+    }
+    Date::from_timestamp(seconds as i64)
+}
+
+impl Date {
+    // the number of the day that starts at noon of this calendar date
+    pub const fn julian_day_number(&self) -> i64 {
+        self.timestamp().div_euclid(SECONDS_PER_DAY as i64) + UNIX_EPOCH_JULIAN_DAY
+    }
+    // noon utc, where the julian day starts
+    pub fn from_julian_day_number(julian_day: i64) -> Result<Date, WBDLError> {
+        julian_day
+            .checked_sub(UNIX_EPOCH_JULIAN_DAY)
+            .and_then(|days| days.checked_mul(SECONDS_PER_DAY as i64))
+            .and_then(|seconds| seconds.checked_add(SECONDS_PER_DAY as i64 / 2))
+            .ok_or(WBDLError)
+            .and_then(Date::from_timestamp)
+    }
+    pub fn julian_date(&self) -> f64 {
+        self.timestamp() as f64 / SECONDS_PER_DAY as f64 + UNIX_EPOCH_JULIAN_DATE
+    }
+    pub fn from_julian_date(julian_date: f64) -> Result<Date, WBDLError> {
+        from_days_since_epoch(julian_date - UNIX_EPOCH_JULIAN_DATE)
+    }
+    pub fn modified_julian_date(&self) -> f64 {
+        self.timestamp() as f64 / SECONDS_PER_DAY as f64 + UNIX_EPOCH_JULIAN_DATE
+            - MODIFIED_JULIAN_DATE_OFFSET
+    }
+    pub fn from_modified_julian_date(modified_julian_date: f64) -> Result<Date, WBDLError> {
+        from_days_since_epoch(
+            modified_julian_date + MODIFIED_JULIAN_DATE_OFFSET - UNIX_EPOCH_JULIAN_DATE,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::Date;
+
+    #[test]
+    pub fn julian_day_number() {
+        let j2000 = date("2000-01-01T12:00:00");
+        assert_eq!(j2000.julian_day_number(), 2_451_545);
+        assert_eq!(date("2000-01-01T00:00:00").julian_day_number(), 2_451_545);
+        assert_eq!(Date::from_julian_day_number(2_451_545), Ok(j2000));
+        assert_eq!(date("1969-12-31T23:59:59").julian_day_number(), 2_440_587);
+        assert!(Date::from_julian_day_number(0).is_err());
+        assert!(Date::from_julian_day_number(i64::MAX).is_err());
+    }
+
+    #[test]
+    pub fn julian_date() {
+        assert_eq!(date("2000-01-01T12:00:00").julian_date(), 2_451_545.0);
+        assert_eq!(date("2000-01-01T18:00:00").julian_date(), 2_451_545.25);
+        assert_eq!(date("1858-11-17T00:00:00").modified_julian_date(), 0.0);
+        assert_eq!(
+            date("2024-02-29T06:00:00").modified_julian_date(),
+            60_369.25
+        );
+        let value = date("2023-12-24T15:04:05");
+        assert_eq!(Date::from_julian_date(value.julian_date()), Ok(value));
+        assert_eq!(
+            Date::from_modified_julian_date(value.modified_julian_date()),
+            Ok(value)
+        );
+        assert!(Date::from_julian_date(f64::NAN).is_err());
+        assert!(Date::from_modified_julian_date(f64::INFINITY).is_err());
+    }
+}
@@ -1,14 +1,88 @@
+pub use anniversary::Anniversaries;
+pub use anniversary::LeapDayPolicy;
+pub use business::BusinessCalendar;
+pub use calendar::Calendar;
+pub use calendar::CalendarDate;
+pub use calendar::HebrewCalendar;
+pub use calendar::IslamicCalendar;
+pub use calendar::JapaneseCalendar;
+pub use calendar::PersianCalendar;
+pub use clock::Clock;
+pub use clock::FixedClock;
+pub use clock::ManualClock;
+pub use clock::OffsetClock;
+pub use clock::SystemClock;
+pub use cron::Cron;
 pub use date::Date;
+pub use duration::Duration;
 pub use error::WBDLError;
+pub use holiday::Holiday;
+pub use holiday::HolidayRule;
+pub use holiday::HolidaySet;
+pub use holiday::MoveableFeast;
+pub use holiday::Observance;
+pub use humanize::HumanizeOptions;
+pub use humanize::TimeUnit;
+pub use interval::Interval;
+pub use interval::IntervalSet;
+pub use iso::IsoDuration;
+pub use iso::IsoInterval;
+pub use iso::Recurrence;
+pub use iso::RecurrenceIter;
+pub use julian::HistoricalCalendar;
+pub use julian::JulianDate;
+pub use julian::SwitchoverPolicy;
+pub use locale::Locale;
+pub use locale::LocaleData;
 pub use month::Month;
 pub use month::Season;
+pub use offset::UtcOffset;
+pub use period::Period;
+pub use range::DateRange;
+pub use range::DateRangeIter;
+pub use range::Step;
+pub use rfc3339::Rfc3339;
+pub use rrule::Frequency;
+pub use rrule::NthWeekday;
+pub use rrule::RRule;
+pub use rrule::RRuleIter;
+pub use rrule::RRuleSet;
 pub use time::Day;
 pub use time::Hour;
+pub use time::Meridiem;
 pub use time::Minute;
 pub use time::Second;
+pub use weekday::Weekday;
 
+mod anniversary;
+mod business;
+mod calendar;
+mod clock;
+mod cron;
 mod date;
+mod duration;
 mod error;
+mod format;
+mod holiday;
+mod http;
+mod humanize;
+mod interval;
+mod iso;
+mod julian;
+mod julian_day;
+mod locale;
 mod month;
+mod natural;
+mod offset;
+mod period;
+mod range;
+mod rfc2822;
+mod rfc3339;
+mod rrule;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(test)]
+mod test_util;
 mod time;
 pub mod util;
+mod weekday;
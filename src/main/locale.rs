@@ -0,0 +1,316 @@
+use crate::error::WBDLError;
+use crate::month::{Month, Season};
+use crate::weekday::Weekday;
+
+// implement this to plug in a locale that is not bundled
+pub trait Locale {
+    fn month_name(&self, month: Month) -> &str;
+    fn month_abbreviation(&self, month: Month) -> &str;
+    fn weekday_name(&self, weekday: Weekday) -> &str;
+    fn weekday_abbreviation(&self, weekday: Weekday) -> &str;
+    fn season_name(&self, season: Season) -> &str;
+    // ante and post meridiem
+    fn meridiem(&self) -> [&str; 2];
+    fn date_pattern(&self) -> &str;
+    fn time_pattern(&self) -> &str;
+    fn date_time_pattern(&self) -> &str;
+    fn uses_12_hour_clock(&self) -> bool {
+        self.time_pattern().contains("%I")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LocaleData {
+    pub tag: &'static str,
+    pub months: [&'static str; 12],
+    pub month_abbreviations: [&'static str; 12],
+    pub weekdays: [&'static str; 7],
+    pub weekday_abbreviations: [&'static str; 7],
+    pub seasons: [&'static str; 4],
+    pub meridiem: [&'static str; 2],
+    pub date_pattern: &'static str,
+    pub time_pattern: &'static str,
+    pub date_time_pattern: &'static str,
+}
+
+impl LocaleData {
+    pub const EN: LocaleData = LocaleData {
+        tag: "en",
+        months: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        month_abbreviations: [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        weekdays: [
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ],
+        weekday_abbreviations: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+        seasons: ["Spring", "Summer", "Autumn", "Winter"],
+        meridiem: ["AM", "PM"],
+        date_pattern: "%m/%d/%Y",
+        time_pattern: "%I:%M:%S %p",
+        date_time_pattern: "%a %b %e %I:%M:%S %p %Y",
+    };
+    pub const DE: LocaleData = LocaleData {
+        tag: "de",
+        months: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        month_abbreviations: [
+            "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+        ],
+        weekdays: [
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+            "Sonntag",
+        ],
+        weekday_abbreviations: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+        seasons: ["Frühling", "Sommer", "Herbst", "Winter"],
+        meridiem: ["AM", "PM"],
+        date_pattern: "%d.%m.%Y",
+        time_pattern: "%H:%M:%S",
+        date_time_pattern: "%a %e. %b %Y %H:%M:%S",
+    };
+    pub const FR: LocaleData = LocaleData {
+        tag: "fr",
+        months: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        month_abbreviations: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        weekdays: [
+            "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+        ],
+        weekday_abbreviations: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+        seasons: ["printemps", "été", "automne", "hiver"],
+        meridiem: ["AM", "PM"],
+        date_pattern: "%d/%m/%Y",
+        time_pattern: "%H:%M:%S",
+        date_time_pattern: "%a %e %b %Y %H:%M:%S",
+    };
+    pub const ES: LocaleData = LocaleData {
+        tag: "es",
+        months: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        month_abbreviations: [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ],
+        weekdays: [
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+            "domingo",
+        ],
+        weekday_abbreviations: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+        seasons: ["primavera", "verano", "otoño", "invierno"],
+        meridiem: ["a. m.", "p. m."],
+        date_pattern: "%d/%m/%Y",
+        time_pattern: "%H:%M:%S",
+        date_time_pattern: "%a %e %b %Y %H:%M:%S",
+    };
+    pub const JA: LocaleData = LocaleData {
+        tag: "ja",
+        months: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        month_abbreviations: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        weekdays: [
+            "月曜日",
+            "火曜日",
+            "水曜日",
+            "木曜日",
+            "金曜日",
+            "土曜日",
+            "日曜日",
+        ],
+        weekday_abbreviations: ["月", "火", "水", "木", "金", "土", "日"],
+        seasons: ["春", "夏", "秋", "冬"],
+        meridiem: ["午前", "午後"],
+        date_pattern: "%Y/%m/%d",
+        time_pattern: "%H:%M:%S",
+        date_time_pattern: "%Y年%m月%d日 %H時%M分%S秒",
+    };
+    pub const BUILTIN: [&'static LocaleData; 5] = [
+        &LocaleData::EN,
+        &LocaleData::DE,
+        &LocaleData::FR,
+        &LocaleData::ES,
+        &LocaleData::JA,
+    ];
+    // looks up a bundled locale by its language tag, a region like de-AT falls back to de
+    pub fn builtin(tag: &str) -> Result<&'static LocaleData, WBDLError> {
+        let language = tag.split(['-', '_']).next().unwrap_or(tag);
+        LocaleData::BUILTIN
+            .into_iter()
+            .find(|locale| locale.tag.eq_ignore_ascii_case(language))
+            .ok_or(WBDLError)
+    }
+}
+
+impl Locale for LocaleData {
+    fn month_name(&self, month: Month) -> &str {
+        self.months[month.ordinal() - 1]
+    }
+    fn month_abbreviation(&self, month: Month) -> &str {
+        self.month_abbreviations[month.ordinal() - 1]
+    }
+    fn weekday_name(&self, weekday: Weekday) -> &str {
+        self.weekdays[weekday.ordinal() - 1]
+    }
+    fn weekday_abbreviation(&self, weekday: Weekday) -> &str {
+        self.weekday_abbreviations[weekday.ordinal() - 1]
+    }
+    fn season_name(&self, season: Season) -> &str {
+        self.seasons[season.ordinal() - 1]
+    }
+    fn meridiem(&self) -> [&str; 2] {
+        self.meridiem
+    }
+    fn date_pattern(&self) -> &str {
+        self.date_pattern
+    }
+    fn time_pattern(&self) -> &str {
+        self.time_pattern
+    }
+    fn date_time_pattern(&self) -> &str {
+        self.date_time_pattern
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, Locale, LocaleData, Month, Season, Weekday};
+
+    struct Pirate;
+
+    impl Locale for Pirate {
+        fn month_name(&self, month: Month) -> &str {
+            LocaleData::EN.month_name(month)
+        }
+        fn month_abbreviation(&self, month: Month) -> &str {
+            LocaleData::EN.month_abbreviation(month)
+        }
+        fn weekday_name(&self, _weekday: Weekday) -> &str {
+            "Arrday"
+        }
+        fn weekday_abbreviation(&self, _weekday: Weekday) -> &str {
+            "Arr"
+        }
+        fn season_name(&self, season: Season) -> &str {
+            LocaleData::EN.season_name(season)
+        }
+        fn meridiem(&self) -> [&str; 2] {
+            ["ayem", "peyem"]
+        }
+        fn date_pattern(&self) -> &str {
+            "%d %B %Y"
+        }
+        fn time_pattern(&self) -> &str {
+            "%I bells %p"
+        }
+        fn date_time_pattern(&self) -> &str {
+            "%A %x, %X"
+        }
+    }
+
+    #[test]
+    pub fn builtin_locales() {
+        let date = Date::try_from("2023-12-24T15:04:05").unwrap();
+        let german = LocaleData::builtin("de-AT").unwrap();
+        assert_eq!(
+            date.format_localized("%A, %e. %B %Y", german).unwrap(),
+            "Sonntag, 24. Dezember 2023"
+        );
+        assert_eq!(
+            date.format_localized("%c", &LocaleData::EN).unwrap(),
+            "Sun Dec 24 03:04:05 PM 2023"
+        );
+        assert_eq!(
+            date.format_localized("%x %X", &LocaleData::FR).unwrap(),
+            "24/12/2023 15:04:05"
+        );
+        assert_eq!(
+            date.format_localized("%c", &LocaleData::JA).unwrap(),
+            "2023年12月24日 15時04分05秒"
+        );
+        assert_eq!(
+            LocaleData::ES.season_name(Month::December.get_season()),
+            "invierno"
+        );
+        assert!(LocaleData::builtin("xx").is_err());
+        assert!(LocaleData::EN.uses_12_hour_clock());
+        assert!(!LocaleData::DE.uses_12_hour_clock());
+    }
+
+    #[test]
+    pub fn custom_locale() {
+        let date = Date::try_from("2023-12-24T00:30:00").unwrap();
+        assert_eq!(
+            date.format_localized("%c", &Pirate).unwrap(),
+            "Arrday 24 December 2023, 12 bells ayem"
+        );
+    }
+}
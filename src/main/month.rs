@@ -1,3 +1,4 @@
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 use crate::error::WBDLError;
@@ -67,6 +68,52 @@ impl Month {
             Month::December => Month::November,
         }
     }
+    pub const fn abbreviation(&self) -> &'static str {
+        match self {
+            Month::January => "Jan",
+            Month::February => "Feb",
+            Month::March => "Mar",
+            Month::April => "Apr",
+            Month::May => "May",
+            Month::June => "Jun",
+            Month::July => "Jul",
+            Month::August => "Aug",
+            Month::September => "Sep",
+            Month::October => "Oct",
+            Month::November => "Nov",
+            Month::December => "Dec",
+        }
+    }
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Month::January => "January",
+            Month::February => "February",
+            Month::March => "March",
+            Month::April => "April",
+            Month::May => "May",
+            Month::June => "June",
+            Month::July => "July",
+            Month::August => "August",
+            Month::September => "September",
+            Month::October => "October",
+            Month::November => "November",
+            Month::December => "December",
+        }
+    }
+    // english three letter abbreviation, case insensitive
+    pub fn from_abbreviation(value: &str) -> Result<Month, WBDLError> {
+        (1..=12)
+            .filter_map(|month| Month::try_from(month).ok())
+            .find(|month| month.abbreviation().eq_ignore_ascii_case(value))
+            .ok_or(WBDLError)
+    }
+    // full or abbreviated english name, case insensitive
+    pub fn from_name(value: &str) -> Result<Month, WBDLError> {
+        (1..=12)
+            .filter_map(|month| Month::try_from(month).ok())
+            .find(|month| month.name().eq_ignore_ascii_case(value))
+            .map_or_else(|| Month::from_abbreviation(value), Ok)
+    }
 }
 
 impl Season {
@@ -75,6 +122,21 @@ impl Season {
     }
     pub const MIN: Season = Season::Spring;
     pub const MAX: Season = Season::Winter;
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Season::Spring => "Spring",
+            Season::Summer => "Summer",
+            Season::Autumn => "Autumn",
+            Season::Winter => "Winter",
+        }
+    }
+    // full english name, case insensitive
+    pub fn from_name(value: &str) -> Result<Season, WBDLError> {
+        (1..=4)
+            .filter_map(|season| Season::try_from(season).ok())
+            .find(|season| season.name().eq_ignore_ascii_case(value))
+            .ok_or(WBDLError)
+    }
 }
 
 impl From<&Month> for Season {
@@ -130,10 +192,50 @@ impl TryFrom<usize> for Month {
     }
 }
 
+// the number of the month or its english name
 impl TryFrom<&str> for Month {
     type Error = WBDLError;
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        Month::try_from(usize::from_str(value).map_err(|_err| WBDLError)?)
+        match usize::from_str(value) {
+            Ok(number) => Month::try_from(number),
+            Err(_err) => Month::from_name(value),
+        }
+    }
+}
+
+impl FromStr for Month {
+    type Err = WBDLError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Month::try_from(s)
+    }
+}
+
+impl Display for Month {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl TryFrom<&str> for Season {
+    type Error = WBDLError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match usize::from_str(value) {
+            Ok(number) => Season::try_from(number),
+            Err(_err) => Season::from_name(value),
+        }
+    }
+}
+
+impl FromStr for Season {
+    type Err = WBDLError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Season::try_from(s)
+    }
+}
+
+impl Display for Season {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.name())
     }
 }
 
@@ -169,6 +271,24 @@ mod tests {
         let string = "14";
         Month::try_from(string).unwrap();
     }
+    #[test]
+    pub fn abbreviation() {
+        assert_eq!(Month::September.abbreviation(), "Sep");
+        assert_eq!(Month::from_abbreviation("dec"), Ok(Month::December));
+        assert!(Month::from_abbreviation("Dez").is_err());
+    }
+
+    #[test]
+    pub fn names() {
+        assert_eq!("january".parse(), Ok(Month::January));
+        assert_eq!(Month::try_from("SEP"), Ok(Month::September));
+        assert_eq!(Month::December.to_string(), "December");
+        assert!("Janu".parse::<Month>().is_err());
+        assert_eq!("autumn".parse(), Ok(Season::Autumn));
+        assert!(Season::try_from("Win").is_err());
+        assert_eq!(Season::Spring.to_string(), "Spring");
+    }
+
     #[test]
     pub fn correct_previous() {
         let first = Month::MIN;
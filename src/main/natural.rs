@@ -0,0 +1,224 @@
+use std::str::FromStr;
+
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::time::{Hour, Meridiem};
+use crate::weekday::Weekday;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Unit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl Unit {
+    fn parse(value: &str) -> Result<Unit, WBDLError> {
+        match value.strip_suffix('s').unwrap_or(value) {
+            "sec" | "second" => Ok(Unit::Seconds),
+            "min" | "minute" => Ok(Unit::Minutes),
+            "hour" => Ok(Unit::Hours),
+            "day" => Ok(Unit::Days),
+            "week" => Ok(Unit::Weeks),
+            "month" => Ok(Unit::Months),
+            "year" => Ok(Unit::Years),
+            _ => Err(WBDLError),
+        }
+    }
+    fn shift(&self, date: Date, count: i64) -> Result<Date, WBDLError> {
+        match self {
+            Unit::Seconds => date.add_seconds(count),
+            Unit::Minutes => date.add_minutes(count),
+            Unit::Hours => date.add_hours(count),
+            Unit::Days => date.add_days(count),
+            Unit::Weeks => date.add_weeks(count),
+            Unit::Months => date.add_months(count),
+            Unit::Years => date.add_years(count),
+        }
+    }
+}
+
+fn count(value: &str) -> Result<i64, WBDLError> {
+    match value {
+        "a" | "an" | "one" => Ok(1),
+        _ if value.bytes().all(|byte| byte.is_ascii_digit()) => {
+            i64::from_str(value).map_err(|_err| WBDLError)
+        }
+        _ => Err(WBDLError),
+    }
+}
+
+// next or last weekday at midnight, otherwise one unit forward or back keeping the time
+fn step(reference: Date, word: &str, forward: bool) -> Result<Date, WBDLError> {
+    match Weekday::from_name(word) {
+        Ok(weekday) if forward => reference.next_weekday(weekday).map(Date::reset_until_hours),
+        Ok(weekday) => reference
+            .previous_weekday(weekday)
+            .map(Date::reset_until_hours),
+        Err(_err) => Unit::parse(word)?.shift(reference, if forward { 1 } else { -1 }),
+    }
+}
+
+// 3pm, 3 pm, 3:30 p.m., 15:30, 15:30:10, noon and midnight
+fn time(tokens: &[&str]) -> Result<[u8; 3], WBDLError> {
+    let (clock, meridiem) = match tokens {
+        ["noon"] => return Ok([12, 0, 0]),
+        ["midnight"] => return Ok([0, 0, 0]),
+        [clock, meridiem] => (*clock, Some(Meridiem::try_from(*meridiem)?)),
+        [clock] => {
+            let length = clock
+                .bytes()
+                .take_while(|byte| !byte.is_ascii_alphabetic())
+                .count();
+            match clock.split_at(length) {
+                (clock, "") => (clock, None),
+                (clock, meridiem) => (clock, Some(Meridiem::try_from(meridiem)?)),
+            }
+        }
+        _ => return Err(WBDLError),
+    };
+    let mut parts = [0u8; 3];
+    let mut length = 0;
+    for part in clock.split(':') {
+        if length == 3 || part.is_empty() || part.len() > 2 {
+            return Err(WBDLError);
+        }
+        parts[length] = u8::from_str(part).map_err(|_err| WBDLError)?;
+        length += 1;
+    }
+    match meridiem {
+        Some(meridiem) => parts[0] = Hour::from_12h(parts[0], meridiem)?.value(),
+        // a bare number is ambiguous without am or pm
+        None if length == 1 => return Err(WBDLError),
+        None => {}
+    }
+    if parts[0] > 23 || parts[1] > 59 || parts[2] > 59 {
+        return Err(WBDLError);
+    }
+    Ok(parts)
+}
+
+impl Date {
+    // common english expressions like "tomorrow 3pm", "next friday" or "2 weeks ago"
+    pub fn parse_natural(value: &str, reference: Date) -> Result<Date, WBDLError> {
+        let value = value.to_ascii_lowercase().replace(',', " ");
+        let tokens = value.split_ascii_whitespace().collect::<Vec<&str>>();
+        let (date, rest) = match tokens.as_slice() {
+            [] => return Err(WBDLError),
+            ["now", rest @ ..] => (reference, rest),
+            ["today", rest @ ..] => (reference.reset_until_hours(), rest),
+            ["tomorrow", rest @ ..] => (reference.add_days(1)?.reset_until_hours(), rest),
+            ["yesterday", rest @ ..] => (reference.add_days(-1)?.reset_until_hours(), rest),
+            ["next", word, rest @ ..] => (step(reference, word, true)?, rest),
+            ["last", word, rest @ ..] => (step(reference, word, false)?, rest),
+            ["in", number, unit, rest @ ..] => {
+                (Unit::parse(unit)?.shift(reference, count(number)?)?, rest)
+            }
+            [number, unit, "ago", rest @ ..] => {
+                (Unit::parse(unit)?.shift(reference, -count(number)?)?, rest)
+            }
+            rest => (reference, rest),
+        };
+        let has_date = rest.len() < tokens.len();
+        let (at, rest) = match rest.strip_prefix(&["at"]) {
+            Some(rest) => (true, rest),
+            None => (false, rest),
+        };
+        if rest.is_empty() {
+            return if has_date && !at {
+                Ok(date)
+            } else {
+                Err(WBDLError)
+            };
+        }
+        let [hour, minute, second] = time(rest)?;
+        Date::new(
+            date.year(),
+            date.month(),
+            date.day().value(),
+            hour,
+            minute,
+            second,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::Date;
+
+    #[test]
+    pub fn relative_days() {
+        // a wednesday
+        let reference = date("2024-01-31T10:20:30");
+        let cases = [
+            ("now", "2024-01-31T10:20:30"),
+            ("Today", "2024-01-31T00:00:00"),
+            ("tomorrow", "2024-02-01T00:00:00"),
+            ("yesterday", "2024-01-30T00:00:00"),
+            ("next friday", "2024-02-02T00:00:00"),
+            ("next wednesday", "2024-02-07T00:00:00"),
+            ("last fri", "2024-01-26T00:00:00"),
+            ("last month", "2023-12-31T10:20:30"),
+            ("next month", "2024-02-29T10:20:30"),
+            ("in 3 days", "2024-02-03T10:20:30"),
+            ("in an hour", "2024-01-31T11:20:30"),
+            ("2 weeks ago", "2024-01-17T10:20:30"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(
+                Date::parse_natural(value, reference),
+                Ok(date(expected)),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    pub fn times() {
+        let reference = date("2024-01-31T10:20:30");
+        let cases = [
+            ("3pm", "2024-01-31T15:00:00"),
+            ("tomorrow 3pm", "2024-02-01T15:00:00"),
+            ("next friday at 9:30 am", "2024-02-02T09:30:00"),
+            ("in 2 days, 18:45", "2024-02-02T18:45:00"),
+            ("today at noon", "2024-01-31T12:00:00"),
+            ("yesterday midnight", "2024-01-30T00:00:00"),
+            ("12am", "2024-01-31T00:00:00"),
+            ("tomorrow 7:15 p.m.", "2024-02-01T19:15:00"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(
+                Date::parse_natural(value, reference),
+                Ok(date(expected)),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    pub fn reject_invalid() {
+        let reference = date("2024-01-31T10:20:30");
+        for value in [
+            "",
+            "soon",
+            "tomorrow at",
+            "at",
+            "3",
+            "13pm",
+            "25:00",
+            "next fortnight",
+            "in days",
+            "tomorrow 3pm extra",
+        ] {
+            assert!(Date::parse_natural(value, reference).is_err(), "{}", value);
+        }
+    }
+}
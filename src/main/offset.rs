@@ -0,0 +1,134 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::util::SECONDS_PER_MINUTE;
+
+const MAX_MINUTES: i16 = 23 * 60 + 59;
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub struct UtcOffset {
+    minutes: i16,
+}
+
+impl UtcOffset {
+    pub const UTC: UtcOffset = UtcOffset { minutes: 0 };
+    pub const fn from_minutes(minutes: i16) -> Result<UtcOffset, WBDLError> {
+        if minutes.unsigned_abs() > MAX_MINUTES as u16 {
+            return Err(WBDLError);
+        }
+        Ok(UtcOffset { minutes })
+    }
+    pub const fn from_hours(hours: i8) -> Result<UtcOffset, WBDLError> {
+        UtcOffset::from_minutes(hours as i16 * 60)
+    }
+    pub const fn as_minutes(&self) -> i16 {
+        self.minutes
+    }
+    pub const fn as_seconds(&self) -> i32 {
+        self.minutes as i32 * SECONDS_PER_MINUTE as i32
+    }
+    pub const fn is_utc(&self) -> bool {
+        self.minutes == 0
+    }
+    pub const fn is_negative(&self) -> bool {
+        self.minutes < 0
+    }
+    pub const fn hours(&self) -> u8 {
+        (self.minutes.unsigned_abs() / 60) as u8
+    }
+    pub const fn minutes(&self) -> u8 {
+        (self.minutes.unsigned_abs() % 60) as u8
+    }
+}
+
+impl Date {
+    // reads self as utc and returns the wall clock time at the offset
+    pub fn to_offset(self, offset: UtcOffset) -> Result<Date, WBDLError> {
+        self.add_seconds(offset.as_seconds() as i64)
+    }
+    // reads self as wall clock time at the offset and returns it in utc
+    pub fn to_utc(self, offset: UtcOffset) -> Result<Date, WBDLError> {
+        self.add_seconds(-offset.as_seconds() as i64)
+    }
+}
+
+impl Display for UtcOffset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.is_negative() { '-' } else { '+' };
+        write!(f, "{}{:02}:{:02}", sign, self.hours(), self.minutes())
+    }
+}
+
+// accepts Z and the numeric forms +HH:MM, +HHMM and +HH
+impl TryFrom<&str> for UtcOffset {
+    type Error = WBDLError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value == "Z" || value == "z" {
+            return Ok(UtcOffset::UTC);
+        }
+        let (negative, rest) = match value.as_bytes().first() {
+            Some(b'+') => (false, &value[1..]),
+            Some(b'-') => (true, &value[1..]),
+            _ => return Err(WBDLError),
+        };
+        if !rest.is_ascii() {
+            return Err(WBDLError);
+        }
+        let (hours, minutes) = match rest.len() {
+            2 => (rest, "00"),
+            4 => rest.split_at(2),
+            5 if rest.as_bytes()[2] == b':' => (&rest[..2], &rest[3..]),
+            _ => return Err(WBDLError),
+        };
+        if !hours
+            .bytes()
+            .chain(minutes.bytes())
+            .all(|byte| byte.is_ascii_digit())
+        {
+            return Err(WBDLError);
+        }
+        let hours = i16::from_str(hours).map_err(|_err| WBDLError)?;
+        let minutes = i16::from_str(minutes).map_err(|_err| WBDLError)?;
+        if hours > 23 || minutes > 59 {
+            return Err(WBDLError);
+        }
+        let total = hours * 60 + minutes;
+        UtcOffset::from_minutes(if negative { -total } else { total })
+    }
+}
+
+impl FromStr for UtcOffset {
+    type Err = WBDLError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UtcOffset::try_from(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, UtcOffset};
+
+    #[test]
+    pub fn parse_and_display() {
+        let offset = UtcOffset::try_from("-05:30").unwrap();
+        assert_eq!(offset.as_minutes(), -330);
+        assert_eq!(offset.to_string(), "-05:30");
+        assert_eq!(UtcOffset::try_from("+0100"), UtcOffset::from_hours(1));
+        assert_eq!(UtcOffset::try_from("Z"), Ok(UtcOffset::UTC));
+        assert_eq!(UtcOffset::UTC.to_string(), "+00:00");
+        assert!(UtcOffset::try_from("+24:00").is_err());
+        assert!(UtcOffset::try_from("+1:00").is_err());
+        assert!(UtcOffset::from_minutes(24 * 60).is_err());
+    }
+
+    #[test]
+    pub fn convert() {
+        let utc = Date::try_from("2023-12-31T23:30:00").unwrap();
+        let tokyo = UtcOffset::from_hours(9).unwrap();
+        let local = utc.to_offset(tokyo).unwrap();
+        assert_eq!(local, Date::try_from("2024-01-01T08:30:00").unwrap());
+        assert_eq!(local.to_utc(tokyo), Ok(utc));
+    }
+}
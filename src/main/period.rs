@@ -0,0 +1,189 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Neg, Sub};
+
+use crate::date::Date;
+use crate::error::WBDLError;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Period {
+    years: i32,
+    months: i32,
+    days: i32,
+}
+
+impl Period {
+    pub const ZERO: Period = Period::new(0, 0, 0);
+    pub const fn new(years: i32, months: i32, days: i32) -> Period {
+        Period {
+            years,
+            months,
+            days,
+        }
+    }
+    pub const fn from_years(years: i32) -> Period {
+        Period::new(years, 0, 0)
+    }
+    pub const fn from_months(months: i32) -> Period {
+        Period::new(0, months, 0)
+    }
+    pub const fn from_weeks(weeks: i32) -> Period {
+        Period::new(0, 0, weeks * 7)
+    }
+    pub const fn from_days(days: i32) -> Period {
+        Period::new(0, 0, days)
+    }
+    pub const fn years(&self) -> i32 {
+        self.years
+    }
+    pub const fn months(&self) -> i32 {
+        self.months
+    }
+    pub const fn days(&self) -> i32 {
+        self.days
+    }
+    pub const fn total_months(&self) -> i64 {
+        self.years as i64 * 12 + self.months as i64
+    }
+    pub const fn is_zero(&self) -> bool {
+        self.years == 0 && self.months == 0 && self.days == 0
+    }
+    pub const fn is_negative(&self) -> bool {
+        self.years < 0 || self.months < 0 || self.days < 0
+    }
+    // folds whole years out of the months, days are left alone as months differ in length
+    pub const fn normalized(&self) -> Period {
+        let months = self.total_months();
+        Period::new((months / 12) as i32, (months % 12) as i32, self.days)
+    }
+}
+
+impl Add for Period {
+    type Output = Period;
+    fn add(self, rhs: Self) -> Self::Output {
+        Period::new(
+            self.years + rhs.years,
+            self.months + rhs.months,
+            self.days + rhs.days,
+        )
+    }
+}
+
+impl Sub for Period {
+    type Output = Period;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Neg for Period {
+    type Output = Period;
+    fn neg(self) -> Self::Output {
+        Period::new(-self.years, -self.months, -self.days)
+    }
+}
+
+impl Display for Period {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<String> = [
+            (self.years, "year"),
+            (self.months, "month"),
+            (self.days, "day"),
+        ]
+        .into_iter()
+        .filter(|(value, _)| *value != 0)
+        .map(|(value, unit)| {
+            if value.abs() == 1 {
+                format!("{} {}", value, unit)
+            } else {
+                format!("{} {}s", value, unit)
+            }
+        })
+        .collect();
+        if parts.is_empty() {
+            write!(f, "0 days")
+        } else {
+            write!(f, "{}", parts.join(", "))
+        }
+    }
+}
+
+impl Date {
+    pub fn add_period(self, period: Period) -> Result<Date, WBDLError> {
+        self.add_months(period.total_months())?
+            .add_days(period.days as i64)
+    }
+    // calendar difference, so 2023-01-31 until 2023-03-01 is 1 month and 1 day
+    pub fn period_until(&self, other: &Date) -> Period {
+        if other < self {
+            return -other.period_until(self);
+        }
+        let mut months = (other.year() as i64 * 12 + other.month().ordinal() as i64)
+            - (self.year() as i64 * 12 + self.month().ordinal() as i64);
+        let mut anchor = self.add_months(months);
+        while anchor.map(|anchor| &anchor > other).unwrap_or(true) && months > 0 {
+            months -= 1;
+            anchor = self.add_months(months);
+        }
+        let days = anchor
+            .map(|anchor| anchor.duration_until(other).as_days())
+            .unwrap_or(0);
+        Period::new((months / 12) as i32, (months % 12) as i32, days as i32)
+    }
+}
+
+impl Add<Period> for Date {
+    type Output = Date;
+    fn add(self, rhs: Period) -> Self::Output {
+        self.add_period(rhs)
+            .expect("overflow when adding period to date")
+    }
+}
+
+impl Sub<Period> for Date {
+    type Output = Date;
+    fn sub(self, rhs: Period) -> Self::Output {
+        self.add_period(-rhs)
+            .expect("overflow when subtracting period from date")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::{Duration, Period};
+
+    #[test]
+    pub fn month_is_not_thirty_days() {
+        let start = date("2023-01-31T09:00:00");
+        assert_eq!(start + Period::from_months(1), date("2023-02-28T09:00:00"));
+        assert_eq!(start + Duration::from_days(30), date("2023-03-02T09:00:00"));
+        assert_eq!(start - Period::new(1, 1, 1), date("2021-12-30T09:00:00"));
+    }
+
+    #[test]
+    pub fn period_until() {
+        let start = date("2020-02-29T12:00:00");
+        let end = date("2022-06-02T12:00:00");
+        assert_eq!(start.period_until(&end), Period::new(2, 3, 4));
+        assert_eq!(end.period_until(&start), Period::new(-2, -3, -4));
+        assert_eq!(start.period_until(&start), Period::ZERO);
+        assert_eq!(
+            date("2023-01-31T00:00:00").period_until(&date("2023-03-01T00:00:00")),
+            Period::new(0, 1, 1)
+        );
+        assert_eq!(
+            date("2023-01-01T12:00:00").period_until(&date("2023-02-01T11:59:59")),
+            Period::new(0, 0, 30)
+        );
+    }
+
+    #[test]
+    pub fn display_and_normalize() {
+        assert_eq!(
+            Period::new(2, 3, 4).to_string(),
+            "2 years, 3 months, 4 days"
+        );
+        assert_eq!(Period::new(0, 1, 0).to_string(), "1 month");
+        assert_eq!(Period::from_months(27).normalized(), Period::new(2, 3, 0));
+    }
+}
@@ -0,0 +1,289 @@
+use std::iter::FusedIterator;
+
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::util::{SECONDS_PER_DAY, SECONDS_PER_HOUR, SECONDS_PER_MINUTE};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Step {
+    Seconds(u64),
+    Minutes(u64),
+    Hours(u64),
+    Days(u64),
+    Weeks(u64),
+    Months(u64),
+    Years(u64),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    start: Date,
+    end: Date,
+    inclusive: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct DateRangeIter {
+    range: DateRange,
+    step: Step,
+    front: u64,
+    back: u64,
+}
+
+impl Step {
+    pub const fn amount(&self) -> u64 {
+        match self {
+            Step::Seconds(amount)
+            | Step::Minutes(amount)
+            | Step::Hours(amount)
+            | Step::Days(amount)
+            | Step::Weeks(amount)
+            | Step::Months(amount)
+            | Step::Years(amount) => *amount,
+        }
+    }
+    pub const fn is_zero(&self) -> bool {
+        self.amount() == 0
+    }
+    // the date `times` steps after `date`, always computed from the start to avoid drift
+    pub fn apply(&self, date: Date, times: u64) -> Result<Date, WBDLError> {
+        let total = self
+            .amount()
+            .checked_mul(times)
+            .and_then(|total| i64::try_from(total).ok())
+            .ok_or(WBDLError)?;
+        match self {
+            Step::Seconds(_) => date.add_seconds(total),
+            Step::Minutes(_) => date.add_minutes(total),
+            Step::Hours(_) => date.add_hours(total),
+            Step::Days(_) => date.add_days(total),
+            Step::Weeks(_) => date.add_weeks(total),
+            Step::Months(_) => date.add_months(total),
+            Step::Years(_) => date.add_years(total),
+        }
+    }
+    fn estimate(&self, start: Date, end: Date) -> u64 {
+        let months = |start: Date, end: Date| {
+            (end.year() as i64 * 12 + end.month().ordinal() as i64)
+                - (start.year() as i64 * 12 + start.month().ordinal() as i64)
+        };
+        let seconds = end.timestamp() - start.timestamp();
+        let distance = match self {
+            Step::Seconds(_) => seconds,
+            Step::Minutes(_) => seconds / SECONDS_PER_MINUTE as i64,
+            Step::Hours(_) => seconds / SECONDS_PER_HOUR as i64,
+            Step::Days(_) => seconds / SECONDS_PER_DAY as i64,
+            Step::Weeks(_) => seconds / (7 * SECONDS_PER_DAY as i64),
+            Step::Months(_) => months(start, end),
+            Step::Years(_) => months(start, end) / 12,
+        };
+        distance.max(0) as u64 / self.amount()
+    }
+}
+
+impl DateRange {
+    pub const fn new(start: Date, end: Date) -> DateRange {
+        DateRange {
+            start,
+            end,
+            inclusive: false,
+        }
+    }
+    pub const fn inclusive(start: Date, end: Date) -> DateRange {
+        DateRange {
+            start,
+            end,
+            inclusive: true,
+        }
+    }
+    pub const fn start(&self) -> Date {
+        self.start
+    }
+    pub const fn end(&self) -> Date {
+        self.end
+    }
+    pub const fn is_inclusive(&self) -> bool {
+        self.inclusive
+    }
+    pub fn is_empty(&self) -> bool {
+        !self.contains(&self.start)
+    }
+    pub fn contains(&self, date: &Date) -> bool {
+        &self.start <= date
+            && if self.inclusive {
+                date <= &self.end
+            } else {
+                date < &self.end
+            }
+    }
+    pub fn step_by(self, step: Step) -> Result<DateRangeIter, WBDLError> {
+        if step.is_zero() {
+            return Err(WBDLError);
+        }
+        let in_range = |times: u64| {
+            step.apply(self.start, times)
+                .map(|date| self.contains(&date))
+                .unwrap_or(false)
+        };
+        let mut count = if self.is_empty() {
+            0
+        } else {
+            step.estimate(self.start, self.end)
+        };
+        while in_range(count) {
+            count += 1;
+        }
+        while count > 0 && !in_range(count - 1) {
+            count -= 1;
+        }
+        Ok(DateRangeIter {
+            range: self,
+            step,
+            front: 0,
+            back: count,
+        })
+    }
+    // the number of dates step_by yields
+    pub fn len(&self, step: Step) -> Result<u64, WBDLError> {
+        self.step_by(step).map(|iter| iter.back - iter.front)
+    }
+    pub fn seconds(self) -> DateRangeIter {
+        self.step_by(Step::Seconds(1)).unwrap()
+    }
+    pub fn days(self) -> DateRangeIter {
+        self.step_by(Step::Days(1)).unwrap()
+    }
+    pub fn weeks(self) -> DateRangeIter {
+        self.step_by(Step::Weeks(1)).unwrap()
+    }
+    pub fn months(self) -> DateRangeIter {
+        self.step_by(Step::Months(1)).unwrap()
+    }
+}
+
+impl DateRangeIter {
+    pub const fn range(&self) -> DateRange {
+        self.range
+    }
+    pub const fn step(&self) -> Step {
+        self.step
+    }
+}
+
+impl Iterator for DateRangeIter {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let date = self.step.apply(self.range.start, self.front).ok();
+        self.front += 1;
+        date
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n as u64).min(self.back);
+        self.next()
+    }
+}
+
+impl DoubleEndedIterator for DateRangeIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.step.apply(self.range.start, self.back).ok()
+    }
+}
+
+impl ExactSizeIterator for DateRangeIter {}
+
+impl FusedIterator for DateRangeIter {}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::{Date, DateRange, Step};
+
+    #[test]
+    pub fn every_day_in_march() {
+        let range = DateRange::new(date("2024-3-1T0:0:0"), date("2024-4-1T0:0:0"));
+        let days: Vec<Date> = range.days().collect();
+        assert_eq!(days.len(), 31);
+        assert_eq!(days.first(), Some(&date("2024-3-1T0:0:0")));
+        assert_eq!(days.last(), Some(&date("2024-3-31T0:0:0")));
+    }
+
+    #[test]
+    pub fn inclusive_includes_end() {
+        let start = date("2024-3-1T0:0:0");
+        let end = date("2024-3-8T0:0:0");
+        assert_eq!(DateRange::new(start, end).days().len(), 7);
+        assert_eq!(DateRange::inclusive(start, end).days().len(), 8);
+        assert_eq!(DateRange::inclusive(start, end).weeks().len(), 2);
+    }
+
+    #[test]
+    pub fn months_keep_day_of_start() {
+        let range = DateRange::inclusive(date("2023-1-31T0:0:0"), date("2023-5-31T0:0:0"));
+        let months: Vec<Date> = range.months().collect();
+        assert_eq!(
+            months,
+            vec![
+                date("2023-1-31T0:0:0"),
+                date("2023-2-28T0:0:0"),
+                date("2023-3-31T0:0:0"),
+                date("2023-4-30T0:0:0"),
+                date("2023-5-31T0:0:0"),
+            ]
+        )
+    }
+
+    #[test]
+    pub fn reverse_iteration() {
+        let range = DateRange::new(date("2023-12-31T23:0:0"), date("2024-1-1T1:0:0"));
+        let mut hours = range.step_by(Step::Minutes(45)).unwrap().rev();
+        assert_eq!(hours.len(), 3);
+        assert_eq!(hours.next(), Some(date("2024-1-1T0:30:0")));
+        assert_eq!(hours.next(), Some(date("2023-12-31T23:45:0")));
+        assert_eq!(hours.next(), Some(date("2023-12-31T23:0:0")));
+        assert_eq!(hours.next(), None);
+    }
+
+    #[test]
+    pub fn contains() {
+        let range = DateRange::new(date("2024-3-1T0:0:0"), date("2024-4-1T0:0:0"));
+        assert!(range.contains(&date("2024-3-15T12:0:0")));
+        assert!(!range.contains(&date("2024-4-1T0:0:0")));
+        assert!(DateRange::inclusive(range.start(), range.end()).contains(&range.end()));
+    }
+
+    #[test]
+    pub fn empty_and_zero_step() {
+        let range = DateRange::new(date("2024-4-1T0:0:0"), date("2024-3-1T0:0:0"));
+        assert!(range.is_empty());
+        assert_eq!(range.days().next(), None);
+        assert!(range.step_by(Step::Days(0)).is_err());
+    }
+
+    #[test]
+    pub fn len() {
+        let range = DateRange::new(date("2024-3-1T0:0:0"), date("2024-4-1T0:0:0"));
+        assert_eq!(range.len(Step::Days(1)), Ok(31));
+        assert_eq!(range.len(Step::Weeks(1)), Ok(5));
+        assert_eq!(range.len(Step::Hours(12)), Ok(62));
+        assert_eq!(range.len(Step::Months(1)), Ok(1));
+        let inclusive = DateRange::inclusive(range.start(), range.end());
+        assert_eq!(inclusive.len(Step::Months(1)), Ok(2));
+        let empty = DateRange::new(range.end(), range.start());
+        assert_eq!(empty.len(Step::Days(1)), Ok(0));
+        assert!(range.len(Step::Days(0)).is_err());
+    }
+}
@@ -0,0 +1,256 @@
+use std::str::FromStr;
+
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::month::Month;
+use crate::offset::UtcOffset;
+use crate::weekday::Weekday;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Token<'a> {
+    Word(&'a str),
+    Number(&'a str),
+    Symbol(char),
+}
+
+// replaces (possibly nested) comments with a space, honouring quoted pairs
+fn strip_comments(value: &str) -> Result<String, WBDLError> {
+    let mut out = String::with_capacity(value.len());
+    let mut depth = 0u32;
+    let mut chars = value.chars();
+    while let Some(char) = chars.next() {
+        match char {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Err(WBDLError),
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    out.push(' ');
+                }
+            }
+            '\\' if depth > 0 => {
+                chars.next().ok_or(WBDLError)?;
+            }
+            _ if depth > 0 => {}
+            _ => out.push(char),
+        }
+    }
+    if depth > 0 {
+        return Err(WBDLError);
+    }
+    Ok(out)
+}
+
+fn tokenize(value: &str) -> Result<Vec<Token<'_>>, WBDLError> {
+    let mut tokens = vec![];
+    let mut rest = value;
+    while let Some(char) = rest.chars().next() {
+        let length = if char.is_ascii_whitespace() {
+            1
+        } else if char.is_ascii_alphabetic() {
+            let length = rest.bytes().take_while(u8::is_ascii_alphabetic).count();
+            tokens.push(Token::Word(&rest[..length]));
+            length
+        } else if char.is_ascii_digit() {
+            let length = rest.bytes().take_while(u8::is_ascii_digit).count();
+            tokens.push(Token::Number(&rest[..length]));
+            length
+        } else if matches!(char, ',' | ':' | '+' | '-') {
+            tokens.push(Token::Symbol(char));
+            1
+        } else {
+            return Err(WBDLError);
+        };
+        rest = &rest[length..];
+    }
+    Ok(tokens)
+}
+
+fn number(token: Option<&Token>, max_digits: usize) -> Result<u32, WBDLError> {
+    match token {
+        Some(Token::Number(digits)) if digits.len() <= max_digits => {
+            u32::from_str(digits).map_err(|_err| WBDLError)
+        }
+        _ => Err(WBDLError),
+    }
+}
+
+// obsolete zone names, military zones are treated as -0000 as RFC 5322 asks
+fn named_zone(name: &str) -> Result<UtcOffset, WBDLError> {
+    let hours = match name.to_ascii_uppercase().as_str() {
+        "UT" | "GMT" => 0,
+        "EDT" => -4,
+        "EST" | "CDT" => -5,
+        "CST" | "MDT" => -6,
+        "MST" | "PDT" => -7,
+        "PST" => -8,
+        military if military.len() == 1 && military != "J" => 0,
+        _ => return Err(WBDLError),
+    };
+    UtcOffset::from_hours(hours)
+}
+
+// RFC 5322 section 3.3 including the obsolete forms of section 4.3
+fn parse(value: &str) -> Result<(Date, UtcOffset), WBDLError> {
+    let stripped = strip_comments(value)?;
+    let tokens = tokenize(&stripped)?;
+    let mut tokens = tokens.iter().peekable();
+    let weekday = match tokens.peek() {
+        Some(Token::Word(name)) => {
+            let weekday = Weekday::from_abbreviation(name)?;
+            tokens.next();
+            if tokens.next() != Some(&Token::Symbol(',')) {
+                return Err(WBDLError);
+            }
+            Some(weekday)
+        }
+        _ => None,
+    };
+    let day = number(tokens.next(), 2)?;
+    let month = match tokens.next() {
+        Some(Token::Word(name)) => Month::from_abbreviation(name)?,
+        _ => return Err(WBDLError),
+    };
+    let year = match tokens.next() {
+        Some(Token::Number(digits)) if digits.len() >= 2 => {
+            let year = u16::from_str(digits).map_err(|_err| WBDLError)?;
+            match digits.len() {
+                2 if year < 50 => year + 2000,
+                2 | 3 => year + 1900,
+                _ => year,
+            }
+        }
+        _ => return Err(WBDLError),
+    };
+    let hour = number(tokens.next(), 2)?;
+    if tokens.next() != Some(&Token::Symbol(':')) {
+        return Err(WBDLError);
+    }
+    let minute = number(tokens.next(), 2)?;
+    let second = if tokens.peek() == Some(&&Token::Symbol(':')) {
+        tokens.next();
+        number(tokens.next(), 2)?
+    } else {
+        0
+    };
+    let offset = match tokens.next() {
+        Some(Token::Symbol(sign @ ('+' | '-'))) => match tokens.next() {
+            Some(Token::Number(digits)) if digits.len() == 4 => {
+                UtcOffset::try_from(format!("{}{}", sign, digits).as_str())?
+            }
+            _ => return Err(WBDLError),
+        },
+        Some(Token::Word(name)) => named_zone(name)?,
+        _ => return Err(WBDLError),
+    };
+    if tokens.next().is_some() || hour > 23 || minute > 59 {
+        return Err(WBDLError);
+    }
+    // the weekday belongs to the day as written, before a leap second moves it on
+    let written = Date::new(year, month, day as u8, 0, 0, 0)?.weekday();
+    if weekday.is_some_and(|weekday| weekday != written) {
+        return Err(WBDLError);
+    }
+    let local = Date::with_leap_second(
+        year,
+        month,
+        day as u8,
+        hour as u8,
+        minute as u8,
+        second as u8,
+    )?;
+    Ok((local, offset))
+}
+
+impl Date {
+    // the instant normalized to utc
+    pub fn parse_rfc2822(value: &str) -> Result<Date, WBDLError> {
+        let (local, offset) = parse(value)?;
+        local.to_utc(offset)
+    }
+    // the wall clock time as written together with its offset
+    pub fn parse_rfc2822_with_offset(value: &str) -> Result<(Date, UtcOffset), WBDLError> {
+        parse(value)
+    }
+    pub fn to_rfc2822(self) -> Result<String, WBDLError> {
+        self.to_rfc2822_with_offset(UtcOffset::UTC)
+    }
+    // reads self as utc and prints the wall clock time at the offset
+    pub fn to_rfc2822_with_offset(self, offset: UtcOffset) -> Result<String, WBDLError> {
+        let local = self.to_offset(offset)?;
+        if local.year() > 9999 {
+            return Err(WBDLError);
+        }
+        Ok(format!(
+            "{}, {} {} {:04} {:02}:{:02}:{:02} {}{:02}{:02}",
+            local.weekday().abbreviation(),
+            local.day(),
+            local.month().abbreviation(),
+            local.year(),
+            local.hour(),
+            local.minute(),
+            local.second(),
+            if offset.is_negative() { '-' } else { '+' },
+            offset.hours(),
+            offset.minutes()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::{Date, UtcOffset};
+
+    #[test]
+    pub fn parse() {
+        assert_eq!(
+            Date::parse_rfc2822("Sun, 24 Dec 2023 12:12:12 +0100"),
+            Ok(date("2023-12-24T11:12:12"))
+        );
+        assert_eq!(
+            Date::parse_rfc2822_with_offset("24 Dec 2023 12:12 -0530"),
+            Ok((
+                date("2023-12-24T12:12:00"),
+                UtcOffset::from_minutes(-330).unwrap()
+            ))
+        );
+        assert!(Date::parse_rfc2822("Mon, 24 Dec 2023 12:12:12 +0100").is_err());
+        assert!(Date::parse_rfc2822("Sun, 24 Dec 2023 12:12:12").is_err());
+        assert!(Date::parse_rfc2822("Sun, 24 Dez 2023 12:12:12 +0100").is_err());
+        assert!(Date::parse_rfc2822("Sun, 24 Dec 2023 12:12:12 +01:00").is_err());
+    }
+
+    #[test]
+    pub fn parse_obsolete() {
+        let expected = Ok(date("1999-02-01T05:30:00"));
+        assert_eq!(Date::parse_rfc2822("Mon, 1 Feb 99 00:30:00 EST"), expected);
+        assert_eq!(Date::parse_rfc2822("1 FEB 099 05:30:00 GMT"), expected);
+        assert_eq!(
+            Date::parse_rfc2822(
+                "Mon (Monday) , 1 Feb 1999 00 : 30 : 00 (a comment (nested)) -0500"
+            ),
+            expected
+        );
+        assert_eq!(Date::parse_rfc2822("1 Feb 1999 05:30:00 z"), expected);
+        assert_eq!(
+            Date::parse_rfc2822("1 Jan 05 00:00:00 UT"),
+            Ok(date("2005-01-01T00:00:00"))
+        );
+        assert!(Date::parse_rfc2822("1 Feb 1999 05:30:00 GMT (unclosed").is_err());
+        assert!(Date::parse_rfc2822("1 Feb 1999 05:30:00 CEST").is_err());
+    }
+
+    #[test]
+    pub fn format() {
+        let value = date("2023-12-04T08:02:03");
+        assert_eq!(
+            value.to_rfc2822().unwrap(),
+            "Mon, 4 Dec 2023 08:02:03 +0000"
+        );
+        let offset = UtcOffset::from_minutes(-210).unwrap();
+        let formatted = value.to_rfc2822_with_offset(offset).unwrap();
+        assert_eq!(formatted, "Mon, 4 Dec 2023 04:32:03 -0330");
+        assert_eq!(Date::parse_rfc2822(&formatted), Ok(value));
+    }
+}
@@ -0,0 +1,290 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::month::Month;
+use crate::offset::UtcOffset;
+
+const NANOS_DIGITS: usize = 9;
+
+// how the offset was written, so parsing and printing round trips
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Designator {
+    Upper,
+    Lower,
+    Numeric,
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rfc3339 {
+    local: Date,
+    separator: char,
+    fraction: String,
+    offset: UtcOffset,
+    designator: Designator,
+    // local is the second after a written 23:59:60, printing restores the 60
+    leap_second: bool,
+}
+
+impl Rfc3339 {
+    pub fn new(local: Date, offset: UtcOffset) -> Result<Rfc3339, WBDLError> {
+        if local.year() > 9999 {
+            return Err(WBDLError);
+        }
+        Ok(Rfc3339 {
+            local,
+            separator: 'T',
+            fraction: String::new(),
+            offset,
+            designator: if offset.is_utc() {
+                Designator::Upper
+            } else {
+                Designator::Numeric
+            },
+            leap_second: false,
+        })
+    }
+    pub fn from_utc(utc: Date, offset: UtcOffset) -> Result<Rfc3339, WBDLError> {
+        Rfc3339::new(utc.to_offset(offset)?, offset)
+    }
+    // -00:00, the time is known in utc but the local offset is not
+    pub fn with_unknown_offset(utc: Date) -> Result<Rfc3339, WBDLError> {
+        let mut value = Rfc3339::new(utc, UtcOffset::UTC)?;
+        value.designator = Designator::Unknown;
+        Ok(value)
+    }
+    pub fn with_separator(mut self, separator: char) -> Result<Rfc3339, WBDLError> {
+        if !matches!(separator, 'T' | 't' | ' ') {
+            return Err(WBDLError);
+        }
+        self.separator = separator;
+        Ok(self)
+    }
+    pub fn with_nanosecond(mut self, nanosecond: u32) -> Result<Rfc3339, WBDLError> {
+        if nanosecond >= 1_000_000_000 {
+            return Err(WBDLError);
+        }
+        let digits = format!("{:09}", nanosecond);
+        self.fraction = digits.trim_end_matches('0').to_string();
+        Ok(self)
+    }
+    // a leap second reads as the first second of the next minute
+    pub const fn local(&self) -> Date {
+        self.local
+    }
+    pub const fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+    pub fn is_offset_unknown(&self) -> bool {
+        self.designator == Designator::Unknown
+    }
+    pub const fn separator(&self) -> char {
+        self.separator
+    }
+    // the digits after the decimal point, as written
+    pub fn fraction(&self) -> &str {
+        &self.fraction
+    }
+    // digits beyond nanosecond precision are truncated
+    pub fn nanosecond(&self) -> u32 {
+        self.fraction
+            .bytes()
+            .chain(std::iter::repeat(b'0'))
+            .take(NANOS_DIGITS)
+            .fold(0, |nanos, digit| nanos * 10 + (digit - b'0') as u32)
+    }
+    pub fn utc(&self) -> Result<Date, WBDLError> {
+        self.local.to_utc(self.offset)
+    }
+}
+
+impl Date {
+    // the instant normalized to utc, fractional seconds are dropped
+    pub fn parse_rfc3339(value: &str) -> Result<Date, WBDLError> {
+        Rfc3339::try_from(value)?.utc()
+    }
+    pub fn to_rfc3339(self) -> Result<String, WBDLError> {
+        Rfc3339::new(self, UtcOffset::UTC).map(|value| value.to_string())
+    }
+}
+
+fn digits(value: &str) -> Result<u32, WBDLError> {
+    if !value.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(WBDLError);
+    }
+    u32::from_str(value).map_err(|_err| WBDLError)
+}
+
+impl TryFrom<&str> for Rfc3339 {
+    type Error = WBDLError;
+    // date-time = full-date "T" full-time, see RFC 3339 section 5.6
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let bytes = value.as_bytes();
+        if !value.is_ascii() || bytes.len() < 20 {
+            return Err(WBDLError);
+        }
+        let separator = bytes[10] as char;
+        if !matches!(separator, 'T' | 't' | ' ')
+            || bytes[4] != b'-'
+            || bytes[7] != b'-'
+            || bytes[13] != b':'
+            || bytes[16] != b':'
+        {
+            return Err(WBDLError);
+        }
+        let (hour, minute, second) = (
+            digits(&value[11..13])?,
+            digits(&value[14..16])?,
+            digits(&value[17..19])?,
+        );
+        if hour > 23 || minute > 59 || second > 60 {
+            return Err(WBDLError);
+        }
+        let leap_second = second == 60;
+        let local = Date::new(
+            digits(&value[0..4])? as u16,
+            Month::try_from(digits(&value[5..7])? as usize)?,
+            digits(&value[8..10])? as u8,
+            hour as u8,
+            minute as u8,
+            second.min(59) as u8,
+        )?;
+        let local = if leap_second {
+            local.add_seconds(1)?
+        } else {
+            local
+        };
+        let mut rest = &value[19..];
+        let mut fraction = String::new();
+        if let Some(after) = rest.strip_prefix('.') {
+            let length = after.bytes().take_while(u8::is_ascii_digit).count();
+            if length == 0 {
+                return Err(WBDLError);
+            }
+            fraction = after[..length].to_string();
+            rest = &after[length..];
+        }
+        let (offset, designator) = match rest {
+            "Z" => (UtcOffset::UTC, Designator::Upper),
+            "z" => (UtcOffset::UTC, Designator::Lower),
+            "-00:00" => (UtcOffset::UTC, Designator::Unknown),
+            _ if rest.len() == 6 => (UtcOffset::try_from(rest)?, Designator::Numeric),
+            _ => return Err(WBDLError),
+        };
+        Ok(Rfc3339 {
+            local,
+            separator,
+            fraction,
+            offset,
+            designator,
+            leap_second,
+        })
+    }
+}
+
+impl FromStr for Rfc3339 {
+    type Err = WBDLError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Rfc3339::try_from(s)
+    }
+}
+
+impl Display for Rfc3339 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (local, second) = match self.leap_second {
+            true => (
+                self.local.add_seconds(-1).map_err(|_err| std::fmt::Error)?,
+                60,
+            ),
+            false => (self.local, self.local.second().value()),
+        };
+        write!(
+            f,
+            "{:04}-{:02}-{:02}{}{:02}:{:02}:{:02}",
+            local.year(),
+            local.month().ordinal(),
+            local.day(),
+            self.separator,
+            local.hour(),
+            local.minute(),
+            second
+        )?;
+        if !self.fraction.is_empty() {
+            write!(f, ".{}", self.fraction)?;
+        }
+        match self.designator {
+            Designator::Upper => write!(f, "Z"),
+            Designator::Lower => write!(f, "z"),
+            Designator::Numeric => write!(f, "{}", self.offset),
+            Designator::Unknown => write!(f, "-00:00"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::{Date, Rfc3339, UtcOffset};
+
+    #[test]
+    pub fn round_trip() {
+        for value in [
+            "1985-04-12T23:20:50.52Z",
+            "1996-12-19T16:39:57-08:00",
+            "1990-12-31T23:59:60Z",
+            "1990-12-31T15:59:60-08:00",
+            "1937-01-01T12:00:27.87+00:20",
+            "2023-12-24t12:12:12.000z",
+            "2023-12-24 12:12:12+00:00",
+            "2023-12-24T12:12:12-00:00",
+        ] {
+            assert_eq!(Rfc3339::try_from(value).unwrap().to_string(), value);
+        }
+    }
+
+    #[test]
+    pub fn leap_second() {
+        let value = Rfc3339::try_from("1990-12-31T23:59:60Z").unwrap();
+        assert_eq!(value.local(), date("1991-01-01T00:00:00"));
+        assert_eq!(value.local().second().value(), 0);
+        assert_eq!(value.utc(), Ok(date("1991-01-01T00:00:00")));
+        assert_eq!(value.to_string(), "1990-12-31T23:59:60Z");
+    }
+
+    #[test]
+    pub fn rejects_loose_forms() {
+        for value in [
+            "2023-12-24T12:12:12",
+            "2023-12-24T12:12:12+0100",
+            "2023-12-24T12:12Z",
+            "2023-1-24T12:12:12Z",
+            "2023-12-24T24:00:00Z",
+            "2023-12-24T12:12:12.Z",
+            "2023-02-30T12:12:12Z",
+            "2023-12-24T+1:12:12Z",
+            "2023-12-24T12:12:12Z ",
+        ] {
+            assert!(Rfc3339::try_from(value).is_err(), "{}", value);
+        }
+    }
+
+    #[test]
+    pub fn normalize_to_utc() {
+        let value = Rfc3339::try_from("1996-12-19T16:39:57.123456789123-08:00").unwrap();
+        assert_eq!(value.nanosecond(), 123_456_789);
+        assert_eq!(value.offset(), UtcOffset::from_hours(-8).unwrap());
+        assert_eq!(
+            Date::parse_rfc3339("1996-12-19T16:39:57-08:00"),
+            Date::try_from("1996-12-20T00:39:57")
+        );
+        let date = Date::try_from("2023-12-24T12:12:12").unwrap();
+        assert_eq!(date.to_rfc3339().unwrap(), "2023-12-24T12:12:12Z");
+        assert_eq!(Date::parse_rfc3339(&date.to_rfc3339().unwrap()), Ok(date));
+        let local = Rfc3339::from_utc(date, UtcOffset::from_minutes(330).unwrap())
+            .and_then(|value| value.with_nanosecond(500_000_000))
+            .unwrap();
+        assert_eq!(local.to_string(), "2023-12-24T17:42:12.5+05:30");
+    }
+}
@@ -0,0 +1,1089 @@
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::iter::FusedIterator;
+use std::str::FromStr;
+
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::month::Month;
+use crate::time::Day;
+use crate::util::{
+    civil_from_days, days_from_civil, get_days_for_year, SECONDS_PER_DAY, SECONDS_PER_HOUR,
+    SECONDS_PER_MINUTE,
+};
+use crate::weekday::Weekday;
+
+// periods in a row without a single occurrence before a rule is considered exhausted
+const MAX_EMPTY_PERIODS: u32 = 10_000;
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Frequency {
+    Secondly,
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+// an entry of BYDAY, `n` of 0 matches every such weekday in the period
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct NthWeekday {
+    n: i8,
+    weekday: Weekday,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RRule {
+    frequency: Frequency,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<Date>,
+    by_second: Vec<u8>,
+    by_minute: Vec<u8>,
+    by_hour: Vec<u8>,
+    by_day: Vec<NthWeekday>,
+    by_month_day: Vec<i8>,
+    by_year_day: Vec<i16>,
+    by_week_no: Vec<i8>,
+    by_month: Vec<Month>,
+    by_set_pos: Vec<i16>,
+    week_start: Weekday,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RRuleSet {
+    dtstart: Date,
+    rrule: RRule,
+    exdates: Vec<Date>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RRuleIter {
+    rule: RRule,
+    dtstart: Date,
+    exdates: Vec<Date>,
+    period: i64,
+    buffer: VecDeque<Date>,
+    emitted: u32,
+    finished: bool,
+}
+
+impl Frequency {
+    const fn name(&self) -> &'static str {
+        match self {
+            Frequency::Secondly => "SECONDLY",
+            Frequency::Minutely => "MINUTELY",
+            Frequency::Hourly => "HOURLY",
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        }
+    }
+    const fn seconds(&self) -> Option<i64> {
+        match self {
+            Frequency::Secondly => Some(1),
+            Frequency::Minutely => Some(SECONDS_PER_MINUTE as i64),
+            Frequency::Hourly => Some(SECONDS_PER_HOUR as i64),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<&str> for Frequency {
+    type Error = WBDLError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "SECONDLY" => Ok(Frequency::Secondly),
+            "MINUTELY" => Ok(Frequency::Minutely),
+            "HOURLY" => Ok(Frequency::Hourly),
+            "DAILY" => Ok(Frequency::Daily),
+            "WEEKLY" => Ok(Frequency::Weekly),
+            "MONTHLY" => Ok(Frequency::Monthly),
+            "YEARLY" => Ok(Frequency::Yearly),
+            _ => Err(WBDLError),
+        }
+    }
+}
+
+impl NthWeekday {
+    pub const fn every(weekday: Weekday) -> NthWeekday {
+        NthWeekday { n: 0, weekday }
+    }
+    pub fn nth(n: i8, weekday: Weekday) -> Result<NthWeekday, WBDLError> {
+        if (-53..=53).contains(&n) {
+            Ok(NthWeekday { n, weekday })
+        } else {
+            Err(WBDLError)
+        }
+    }
+    pub const fn n(&self) -> i8 {
+        self.n
+    }
+    pub const fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    ["MO", "TU", "WE", "TH", "FR", "SA", "SU"][weekday.ordinal() - 1]
+}
+
+fn parse_weekday_code(value: &str) -> Result<Weekday, WBDLError> {
+    ["MO", "TU", "WE", "TH", "FR", "SA", "SU"]
+        .iter()
+        .position(|code| code == &value)
+        .ok_or(WBDLError)
+        .and_then(|index| Weekday::try_from(index + 1))
+}
+
+impl TryFrom<&str> for NthWeekday {
+    type Error = WBDLError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value.len() < 2 || !value.is_char_boundary(value.len() - 2) {
+            return Err(WBDLError);
+        }
+        let (n, weekday) = value.split_at(value.len() - 2);
+        let weekday = parse_weekday_code(weekday)?;
+        match n {
+            "" => Ok(NthWeekday::every(weekday)),
+            _ => {
+                let n = i8::from_str(n).map_err(|_err| WBDLError)?;
+                if n == 0 {
+                    return Err(WBDLError);
+                }
+                NthWeekday::nth(n, weekday)
+            }
+        }
+    }
+}
+
+impl Display for NthWeekday {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.n != 0 {
+            write!(f, "{}", self.n)?;
+        }
+        write!(f, "{}", weekday_code(self.weekday))
+    }
+}
+
+// a date only value is midnight, or the last second of the day for UNTIL
+fn parse_ical_date(value: &str, whole_day: bool) -> Result<Date, WBDLError> {
+    let value = value.strip_suffix('Z').unwrap_or(value);
+    let (date, time) = value.split_once('T').unwrap_or((value, ""));
+    let number = |value: &str| u16::from_str(value).map_err(|_err| WBDLError);
+    if date.len() != 8 || !date.is_ascii() || !(time.is_empty() || time.len() == 6) {
+        return Err(WBDLError);
+    }
+    let year = number(&date[..4])?;
+    let month = Month::try_from(number(&date[4..6])? as usize)?;
+    let day = number(&date[6..])? as u8;
+    if time.is_empty() && whole_day {
+        return Date::new(year, month, day, 23, 59, 59);
+    }
+    if time.is_empty() {
+        return Date::new(year, month, day, 0, 0, 0);
+    }
+    if !time.is_ascii() {
+        return Err(WBDLError);
+    }
+    Date::new(
+        year,
+        month,
+        day,
+        number(&time[..2])? as u8,
+        number(&time[2..4])? as u8,
+        number(&time[4..])? as u8,
+    )
+}
+
+fn format_ical_date(date: &Date) -> String {
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+        date.year(),
+        date.month().ordinal(),
+        date.day(),
+        date.hour(),
+        date.minute(),
+        date.second()
+    )
+}
+
+fn parse_list<T>(
+    value: &str,
+    parse: impl Fn(&str) -> Result<T, WBDLError>,
+) -> Result<Vec<T>, WBDLError> {
+    value.split(',').map(parse).collect()
+}
+
+// signed ranges never include zero, as in BYMONTHDAY=-1
+fn parse_number<T: FromStr + PartialOrd + Default + Copy>(
+    min: T,
+    max: T,
+) -> impl Fn(&str) -> Result<T, WBDLError> {
+    move |value| {
+        let number = T::from_str(value).map_err(|_err| WBDLError)?;
+        let signed = min < T::default();
+        if number >= min && number <= max && !(signed && number == T::default()) {
+            Ok(number)
+        } else {
+            Err(WBDLError)
+        }
+    }
+}
+
+impl RRule {
+    pub const fn new(frequency: Frequency) -> RRule {
+        RRule {
+            frequency,
+            interval: 1,
+            count: None,
+            until: None,
+            by_second: vec![],
+            by_minute: vec![],
+            by_hour: vec![],
+            by_day: vec![],
+            by_month_day: vec![],
+            by_year_day: vec![],
+            by_week_no: vec![],
+            by_month: vec![],
+            by_set_pos: vec![],
+            week_start: Weekday::Monday,
+        }
+    }
+    pub fn with_interval(mut self, interval: u32) -> Result<RRule, WBDLError> {
+        if interval == 0 {
+            return Err(WBDLError);
+        }
+        self.interval = interval;
+        Ok(self)
+    }
+    pub fn with_count(mut self, count: u32) -> Result<RRule, WBDLError> {
+        if self.until.is_some() {
+            return Err(WBDLError);
+        }
+        self.count = Some(count);
+        Ok(self)
+    }
+    pub fn with_until(mut self, until: Date) -> Result<RRule, WBDLError> {
+        if self.count.is_some() {
+            return Err(WBDLError);
+        }
+        self.until = Some(until);
+        Ok(self)
+    }
+    pub fn with_by_day(mut self, by_day: Vec<NthWeekday>) -> Result<RRule, WBDLError> {
+        self.by_day = by_day;
+        self.validate()
+    }
+    pub fn with_by_month_day(mut self, by_month_day: Vec<i8>) -> Result<RRule, WBDLError> {
+        self.by_month_day = by_month_day;
+        self.validate()
+    }
+    pub fn with_by_month(mut self, by_month: Vec<Month>) -> Result<RRule, WBDLError> {
+        self.by_month = by_month;
+        self.validate()
+    }
+    pub fn with_by_set_pos(mut self, by_set_pos: Vec<i16>) -> Result<RRule, WBDLError> {
+        self.by_set_pos = by_set_pos;
+        self.validate()
+    }
+    pub const fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+    pub const fn interval(&self) -> u32 {
+        self.interval
+    }
+    pub const fn count(&self) -> Option<u32> {
+        self.count
+    }
+    pub const fn until(&self) -> Option<Date> {
+        self.until
+    }
+    pub fn by_second(&self) -> &[u8] {
+        &self.by_second
+    }
+    pub fn by_minute(&self) -> &[u8] {
+        &self.by_minute
+    }
+    pub fn by_hour(&self) -> &[u8] {
+        &self.by_hour
+    }
+    pub fn by_day(&self) -> &[NthWeekday] {
+        &self.by_day
+    }
+    pub fn by_month_day(&self) -> &[i8] {
+        &self.by_month_day
+    }
+    pub fn by_year_day(&self) -> &[i16] {
+        &self.by_year_day
+    }
+    pub fn by_week_no(&self) -> &[i8] {
+        &self.by_week_no
+    }
+    pub fn by_month(&self) -> &[Month] {
+        &self.by_month
+    }
+    pub fn by_set_pos(&self) -> &[i16] {
+        &self.by_set_pos
+    }
+    pub const fn week_start(&self) -> Weekday {
+        self.week_start
+    }
+    pub fn iter(&self, dtstart: Date) -> RRuleIter {
+        RRuleIter::new(self.clone(), dtstart, vec![])
+    }
+    // the combinations RFC 5545 forbids
+    fn validate(self) -> Result<RRule, WBDLError> {
+        let numbered_day = self.by_day.iter().any(|day| day.n != 0);
+        let invalid = (numbered_day
+            && (!matches!(self.frequency, Frequency::Monthly | Frequency::Yearly)
+                || !self.by_week_no.is_empty()))
+            || (!self.by_week_no.is_empty() && self.frequency != Frequency::Yearly)
+            || (!self.by_year_day.is_empty()
+                && matches!(
+                    self.frequency,
+                    Frequency::Daily | Frequency::Weekly | Frequency::Monthly
+                ))
+            || (!self.by_month_day.is_empty() && self.frequency == Frequency::Weekly)
+            || (!self.by_set_pos.is_empty()
+                && self.by_second.is_empty()
+                && self.by_minute.is_empty()
+                && self.by_hour.is_empty()
+                && self.by_day.is_empty()
+                && self.by_month_day.is_empty()
+                && self.by_year_day.is_empty()
+                && self.by_week_no.is_empty()
+                && self.by_month.is_empty());
+        if invalid {
+            Err(WBDLError)
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+impl TryFrom<&str> for RRule {
+    type Error = WBDLError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        let value = value.strip_prefix("RRULE:").unwrap_or(value);
+        let mut frequency = None;
+        let mut rule = RRule::new(Frequency::Yearly);
+        let mut seen = vec![];
+        for part in value.split(';') {
+            let (name, value) = part.split_once('=').ok_or(WBDLError)?;
+            if seen.contains(&name) || value.is_empty() {
+                return Err(WBDLError);
+            }
+            seen.push(name);
+            match name {
+                "FREQ" => frequency = Some(Frequency::try_from(value)?),
+                "INTERVAL" => {
+                    rule.interval = parse_number(1, u32::MAX)(value)?;
+                }
+                "COUNT" => rule.count = Some(parse_number(1, u32::MAX)(value)?),
+                "UNTIL" => rule.until = Some(parse_ical_date(value, true)?),
+                "BYSECOND" => rule.by_second = parse_list(value, parse_number(0, 59))?,
+                "BYMINUTE" => rule.by_minute = parse_list(value, parse_number(0, 59))?,
+                "BYHOUR" => rule.by_hour = parse_list(value, parse_number(0, 23))?,
+                "BYDAY" => rule.by_day = parse_list(value, |value| NthWeekday::try_from(value))?,
+                "BYMONTHDAY" => rule.by_month_day = parse_list(value, parse_number(-31, 31))?,
+                "BYYEARDAY" => rule.by_year_day = parse_list(value, parse_number(-366, 366))?,
+                "BYWEEKNO" => rule.by_week_no = parse_list(value, parse_number(-53, 53))?,
+                "BYMONTH" => {
+                    rule.by_month = parse_list(value, |value| {
+                        Month::try_from(parse_number(1usize, 12)(value)?)
+                    })?
+                }
+                "BYSETPOS" => rule.by_set_pos = parse_list(value, parse_number(-366, 366))?,
+                "WKST" => rule.week_start = parse_weekday_code(value)?,
+                _ => return Err(WBDLError),
+            }
+        }
+        rule.frequency = frequency.ok_or(WBDLError)?;
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err(WBDLError);
+        }
+        rule.validate()
+    }
+}
+
+impl FromStr for RRule {
+    type Err = WBDLError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl Display for RRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn list<T: Display>(values: &[T]) -> String {
+            values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<String>>()
+                .join(",")
+        }
+        write!(f, "FREQ={}", self.frequency.name())?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        if let Some(until) = &self.until {
+            write!(f, ";UNTIL={}", format_ical_date(until))?;
+        }
+        let months: Vec<usize> = self.by_month.iter().map(Month::ordinal).collect();
+        for (name, values) in [
+            ("BYSECOND", list(&self.by_second)),
+            ("BYMINUTE", list(&self.by_minute)),
+            ("BYHOUR", list(&self.by_hour)),
+            ("BYDAY", list(&self.by_day)),
+            ("BYMONTHDAY", list(&self.by_month_day)),
+            ("BYYEARDAY", list(&self.by_year_day)),
+            ("BYWEEKNO", list(&self.by_week_no)),
+            ("BYMONTH", list(&months)),
+            ("BYSETPOS", list(&self.by_set_pos)),
+        ] {
+            if !values.is_empty() {
+                write!(f, ";{}={}", name, values)?;
+            }
+        }
+        if self.week_start != Weekday::Monday {
+            write!(f, ";WKST={}", weekday_code(self.week_start))?;
+        }
+        Ok(())
+    }
+}
+
+impl RRuleSet {
+    pub const fn new(dtstart: Date, rrule: RRule) -> RRuleSet {
+        RRuleSet {
+            dtstart,
+            rrule,
+            exdates: vec![],
+        }
+    }
+    pub fn with_exdate(mut self, exdate: Date) -> RRuleSet {
+        self.exdates.push(exdate);
+        self
+    }
+    pub const fn dtstart(&self) -> Date {
+        self.dtstart
+    }
+    pub const fn rrule(&self) -> &RRule {
+        &self.rrule
+    }
+    pub fn exdates(&self) -> &[Date] {
+        &self.exdates
+    }
+    pub fn iter(&self) -> RRuleIter {
+        RRuleIter::new(self.rrule.clone(), self.dtstart, self.exdates.clone())
+    }
+}
+
+impl TryFrom<&str> for RRuleSet {
+    type Error = WBDLError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (mut dtstart, mut rrule, mut exdates) = (None, None, vec![]);
+        for line in value.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (name, value) = line.split_once(':').ok_or(WBDLError)?;
+            // property parameters such as VALUE=DATE or TZID are not interpreted
+            match name.split(';').next() {
+                Some("DTSTART") if dtstart.is_none() => {
+                    dtstart = Some(parse_ical_date(value, false)?)
+                }
+                Some("RRULE") if rrule.is_none() => rrule = Some(RRule::try_from(value)?),
+                Some("EXDATE") => exdates.append(&mut parse_list(value, |value| {
+                    parse_ical_date(value, false)
+                })?),
+                _ => return Err(WBDLError),
+            }
+        }
+        Ok(RRuleSet {
+            dtstart: dtstart.ok_or(WBDLError)?,
+            rrule: rrule.ok_or(WBDLError)?,
+            exdates,
+        })
+    }
+}
+
+impl FromStr for RRuleSet {
+    type Err = WBDLError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl Display for RRuleSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DTSTART:{}\nRRULE:{}",
+            format_ical_date(&self.dtstart),
+            self.rrule
+        )?;
+        if !self.exdates.is_empty() {
+            let exdates: Vec<String> = self.exdates.iter().map(format_ical_date).collect();
+            write!(f, "\nEXDATE:{}", exdates.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+impl IntoIterator for RRuleSet {
+    type Item = Date;
+    type IntoIter = RRuleIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RRuleIter::new(self.rrule, self.dtstart, self.exdates)
+    }
+}
+
+const fn days_in_month(year: u16, month: u8) -> u8 {
+    Day::get_days_per_month(year)[month as usize]
+}
+
+// first day of week 1, the first week with at least four days in the year
+fn first_week_start(year: i64, week_start: Weekday) -> i64 {
+    let first = days_from_civil(year as u16, 1, 1);
+    let offset = Weekday::from_days(first).days_until(week_start) as i64;
+    let offset = (7 - offset) % 7;
+    if 7 - offset >= 4 {
+        first - offset
+    } else {
+        first - offset + 7
+    }
+}
+
+// [week number, weeks in that year] following RFC 5545 week numbering
+fn week_number(day: i64, year: i64, week_start: Weekday) -> [i64; 2] {
+    let weeks = |year: i64| {
+        (first_week_start(year + 1, week_start) - first_week_start(year, week_start)) / 7
+    };
+    let start = first_week_start(year, week_start);
+    if day < start {
+        [weeks(year - 1), weeks(year - 1)]
+    } else if day >= first_week_start(year + 1, week_start) {
+        [1, weeks(year + 1)]
+    } else {
+        [(day - start) / 7 + 1, weeks(year)]
+    }
+}
+
+impl RRuleIter {
+    fn new(mut rule: RRule, dtstart: Date, mut exdates: Vec<Date>) -> RRuleIter {
+        let start_day =
+            days_from_civil(dtstart.year(), dtstart.month() as u8, dtstart.day().value());
+        // fill in the parts RFC 5545 takes from DTSTART
+        match rule.frequency {
+            Frequency::Yearly
+                if rule.by_week_no.is_empty()
+                    && rule.by_year_day.is_empty()
+                    && rule.by_month_day.is_empty()
+                    && rule.by_day.is_empty() =>
+            {
+                if rule.by_month.is_empty() {
+                    rule.by_month.push(dtstart.month());
+                }
+                rule.by_month_day.push(dtstart.day().value() as i8);
+            }
+            Frequency::Yearly
+                if !rule.by_week_no.is_empty()
+                    && rule.by_year_day.is_empty()
+                    && rule.by_month_day.is_empty()
+                    && rule.by_day.is_empty() =>
+            {
+                rule.by_day
+                    .push(NthWeekday::every(Weekday::from_days(start_day)));
+            }
+            Frequency::Monthly if rule.by_month_day.is_empty() && rule.by_day.is_empty() => {
+                rule.by_month_day.push(dtstart.day().value() as i8);
+            }
+            Frequency::Weekly if rule.by_day.is_empty() => {
+                rule.by_day
+                    .push(NthWeekday::every(Weekday::from_days(start_day)));
+            }
+            _ => {}
+        }
+        if rule.by_hour.is_empty() && rule.frequency >= Frequency::Daily {
+            rule.by_hour.push(dtstart.hour().value());
+        }
+        if rule.by_minute.is_empty() && rule.frequency >= Frequency::Hourly {
+            rule.by_minute.push(dtstart.minute().value());
+        }
+        if rule.by_second.is_empty() && rule.frequency >= Frequency::Minutely {
+            rule.by_second.push(dtstart.second().value());
+        }
+        rule.by_hour.sort_unstable();
+        rule.by_hour.dedup();
+        rule.by_minute.sort_unstable();
+        rule.by_minute.dedup();
+        rule.by_second.sort_unstable();
+        rule.by_second.dedup();
+        exdates.sort_unstable();
+        RRuleIter {
+            rule,
+            dtstart,
+            exdates,
+            period: 0,
+            buffer: VecDeque::new(),
+            emitted: 0,
+            finished: false,
+        }
+    }
+
+    fn start_day(&self) -> i64 {
+        days_from_civil(
+            self.dtstart.year(),
+            self.dtstart.month() as u8,
+            self.dtstart.day().value(),
+        )
+    }
+
+    fn matches_day(&self, day: i64) -> bool {
+        let rule = &self.rule;
+        let [year, month, month_day] = civil_from_days(day);
+        let (year16, month8) = (year as u16, month as u8);
+        let month_length = days_in_month(year16, month8) as i64;
+        let year_length = get_days_for_year(year16) as i64;
+        let year_day = day - days_from_civil(year16, 1, 1) + 1;
+        if !rule.by_month.is_empty() && !rule.by_month.iter().any(|value| *value as i64 == month) {
+            return false;
+        }
+        if !rule.by_week_no.is_empty() {
+            let [week, weeks] = week_number(day, year, rule.week_start);
+            if !rule
+                .by_week_no
+                .iter()
+                .any(|value| *value as i64 == week || *value as i64 == week - weeks - 1)
+            {
+                return false;
+            }
+        }
+        if !rule.by_year_day.is_empty()
+            && !rule.by_year_day.iter().any(|value| {
+                *value as i64 == year_day || *value as i64 == year_day - year_length - 1
+            })
+        {
+            return false;
+        }
+        if !rule.by_month_day.is_empty()
+            && !rule.by_month_day.iter().any(|value| {
+                *value as i64 == month_day || *value as i64 == month_day - month_length - 1
+            })
+        {
+            return false;
+        }
+        if !rule.by_day.is_empty() {
+            let weekday = Weekday::from_days(day);
+            // ordinals count within the month unless a yearly rule has no BYMONTH
+            let (position, length) =
+                if rule.frequency == Frequency::Yearly && rule.by_month.is_empty() {
+                    (year_day, year_length)
+                } else {
+                    (month_day, month_length)
+                };
+            let nth = (position - 1) / 7 + 1;
+            let nth_last = -((length - position) / 7 + 1);
+            if !rule.by_day.iter().any(|value| {
+                value.weekday == weekday
+                    && (value.n == 0 || value.n as i64 == nth || value.n as i64 == nth_last)
+            }) {
+                return false;
+            }
+        }
+        true
+    }
+
+    // first day after `day` that could match, skipping months excluded by BYMONTH
+    fn skip_day(&self, day: i64) -> i64 {
+        let [year, month, _] = civil_from_days(day);
+        if self.rule.by_month.is_empty()
+            || self
+                .rule
+                .by_month
+                .iter()
+                .any(|value| *value as i64 == month)
+        {
+            return day + 1;
+        }
+        let (year, month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+        if year > u16::MAX as i64 {
+            return i64::MAX / 2;
+        }
+        days_from_civil(year as u16, month as u8, 1)
+    }
+
+    // days covered by the current period, or None once it left the range of Date
+    fn period_days(&self) -> Option<(i64, i64)> {
+        let interval = self.rule.interval as i64;
+        let step = self.period.checked_mul(interval)?;
+        match self.rule.frequency {
+            Frequency::Yearly => {
+                let year = u16::try_from(self.dtstart.year() as i64 + step).ok()?;
+                let first = days_from_civil(year, 1, 1);
+                Some((first, first + get_days_for_year(year) as i64))
+            }
+            Frequency::Monthly => {
+                let months =
+                    self.dtstart.year() as i64 * 12 + self.dtstart.month() as i64 - 1 + step;
+                let year = u16::try_from(months.div_euclid(12)).ok()?;
+                let month = (months.rem_euclid(12) + 1) as u8;
+                let first = days_from_civil(year, month, 1);
+                Some((first, first + days_in_month(year, month) as i64))
+            }
+            Frequency::Weekly => {
+                let start = self.start_day();
+                let offset = self.rule.week_start.days_until(Weekday::from_days(start)) as i64;
+                let first = start - offset + step * 7;
+                Some((first, first + 7))
+            }
+            _ => None,
+        }
+    }
+
+    fn times(&self, day: i64) -> Vec<i64> {
+        let rule = &self.rule;
+        let mut times =
+            Vec::with_capacity(rule.by_hour.len() * rule.by_minute.len() * rule.by_second.len());
+        for hour in &rule.by_hour {
+            for minute in &rule.by_minute {
+                for second in &rule.by_second {
+                    times.push(
+                        day * SECONDS_PER_DAY as i64
+                            + *hour as i64 * SECONDS_PER_HOUR as i64
+                            + *minute as i64 * SECONDS_PER_MINUTE as i64
+                            + *second as i64,
+                    );
+                }
+            }
+        }
+        times
+    }
+
+    // candidates of the current period and the period to look at next
+    fn expand(&self) -> Option<(Vec<i64>, i64)> {
+        let rule = &self.rule;
+        if let Some(unit) = rule.frequency.seconds() {
+            let start = self.dtstart.timestamp();
+            let step = unit.checked_mul(rule.interval as i64)?;
+            let base = start - start.rem_euclid(unit);
+            let current = base.checked_add(self.period.checked_mul(step)?)?;
+            if current >= (days_from_civil(u16::MAX, 12, 31) + 1) * SECONDS_PER_DAY as i64 {
+                return None;
+            }
+            let day = current.div_euclid(SECONDS_PER_DAY as i64);
+            let seconds = current.rem_euclid(SECONDS_PER_DAY as i64);
+            let hour = (seconds / SECONDS_PER_HOUR as i64) as u8;
+            let minute = (seconds % SECONDS_PER_HOUR as i64 / SECONDS_PER_MINUTE as i64) as u8;
+            let second = (seconds % SECONDS_PER_MINUTE as i64) as u8;
+            let allows = |values: &[u8], value: u8| values.is_empty() || values.contains(&value);
+            let next = |boundary: i64| {
+                (self.period + 1).max((boundary - base + step - 1).div_euclid(step))
+            };
+            if !self.matches_day(day) {
+                return Some((vec![], next(self.skip_day(day) * SECONDS_PER_DAY as i64)));
+            }
+            let hour_start = current - current.rem_euclid(SECONDS_PER_HOUR as i64);
+            let minute_start = current - current.rem_euclid(SECONDS_PER_MINUTE as i64);
+            let candidates = match rule.frequency {
+                Frequency::Hourly if allows(&rule.by_hour, hour) => {
+                    let mut candidates = vec![];
+                    for minute in &rule.by_minute {
+                        for second in &rule.by_second {
+                            candidates.push(
+                                hour_start
+                                    + *minute as i64 * SECONDS_PER_MINUTE as i64
+                                    + *second as i64,
+                            );
+                        }
+                    }
+                    candidates
+                }
+                Frequency::Minutely if !allows(&rule.by_hour, hour) => {
+                    return Some((vec![], next(hour_start + SECONDS_PER_HOUR as i64)));
+                }
+                Frequency::Minutely if allows(&rule.by_minute, minute) => rule
+                    .by_second
+                    .iter()
+                    .map(|second| minute_start + *second as i64)
+                    .collect(),
+                Frequency::Secondly if !allows(&rule.by_hour, hour) => {
+                    return Some((vec![], next(hour_start + SECONDS_PER_HOUR as i64)));
+                }
+                Frequency::Secondly if !allows(&rule.by_minute, minute) => {
+                    return Some((vec![], next(minute_start + SECONDS_PER_MINUTE as i64)));
+                }
+                Frequency::Secondly if allows(&rule.by_second, second) => vec![current],
+                _ => vec![],
+            };
+            return Some((candidates, self.period + 1));
+        }
+        if rule.frequency == Frequency::Daily {
+            let day = self
+                .start_day()
+                .checked_add(self.period.checked_mul(rule.interval as i64)?)?;
+            if day > days_from_civil(u16::MAX, 12, 31) {
+                return None;
+            }
+            if !self.matches_day(day) {
+                let skip = self.skip_day(day) - self.start_day();
+                let next =
+                    (self.period + 1).max((skip + rule.interval as i64 - 1) / rule.interval as i64);
+                return Some((vec![], next));
+            }
+            return Some((self.times(day), self.period + 1));
+        }
+        let (first, last) = self.period_days()?;
+        let candidates = (first..last)
+            .filter(|day| self.matches_day(*day))
+            .flat_map(|day| self.times(day))
+            .collect();
+        Some((candidates, self.period + 1))
+    }
+
+    fn fill(&mut self) {
+        let mut empty = 0;
+        while self.buffer.is_empty() && !self.finished {
+            let Some((mut candidates, next)) = self.expand() else {
+                self.finished = true;
+                return;
+            };
+            self.period = next;
+            if !self.rule.by_set_pos.is_empty() {
+                let length = candidates.len() as i64;
+                let mut selected: Vec<i64> = self
+                    .rule
+                    .by_set_pos
+                    .iter()
+                    .map(|position| {
+                        if *position > 0 {
+                            *position as i64 - 1
+                        } else {
+                            length + *position as i64
+                        }
+                    })
+                    .filter(|index| (0..length).contains(index))
+                    .map(|index| candidates[index as usize])
+                    .collect();
+                selected.sort_unstable();
+                selected.dedup();
+                candidates = selected;
+            }
+            let start = self.dtstart.timestamp();
+            let dates: Vec<Date> = candidates
+                .into_iter()
+                .filter(|candidate| *candidate >= start)
+                .filter_map(|candidate| Date::from_timestamp(candidate).ok())
+                .collect();
+            if dates.is_empty() {
+                empty += 1;
+                if empty >= MAX_EMPTY_PERIODS {
+                    self.finished = true;
+                }
+            }
+            self.buffer.extend(dates);
+        }
+    }
+}
+
+impl Iterator for RRuleIter {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.rule.count.is_some_and(|count| self.emitted >= count) {
+                return None;
+            }
+            self.fill();
+            let date = self.buffer.pop_front()?;
+            if self.rule.until.is_some_and(|until| date > until) {
+                self.finished = true;
+                self.buffer.clear();
+                return None;
+            }
+            self.emitted += 1;
+            if self.exdates.binary_search(&date).is_err() {
+                return Some(date);
+            }
+        }
+    }
+}
+
+impl FusedIterator for RRuleIter {}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::date;
+    use crate::{Date, RRule, RRuleSet};
+
+    fn expand(rule: &str, start: &str, take: usize) -> Vec<String> {
+        RRule::try_from(rule)
+            .unwrap()
+            .iter(date(start))
+            .take(take)
+            .map(|date| date.format("%FT%T").unwrap())
+            .collect()
+    }
+
+    #[test]
+    pub fn second_tuesday_monthly() {
+        let dates = expand("FREQ=MONTHLY;BYDAY=2TU;COUNT=10", "2024-01-01T09:00:00", 20);
+        assert_eq!(dates.len(), 10);
+        assert_eq!(dates[0], "2024-01-09T09:00:00");
+        assert_eq!(dates[1], "2024-02-13T09:00:00");
+        assert_eq!(dates[9], "2024-10-08T09:00:00");
+    }
+
+    #[test]
+    pub fn last_weekday_of_month_with_set_pos() {
+        let dates = expand(
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1;COUNT=3",
+            "2023-09-01T17:00:00",
+            5,
+        );
+        assert_eq!(
+            dates,
+            vec![
+                "2023-09-29T17:00:00",
+                "2023-10-31T17:00:00",
+                "2023-11-30T17:00:00"
+            ]
+        );
+    }
+
+    #[test]
+    pub fn until_is_inclusive() {
+        let dates = expand(
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR;UNTIL=20240122T090000Z",
+            "2024-01-01T09:00:00",
+            20,
+        );
+        assert_eq!(
+            dates,
+            vec![
+                "2024-01-01T09:00:00",
+                "2024-01-05T09:00:00",
+                "2024-01-15T09:00:00",
+                "2024-01-19T09:00:00"
+            ]
+        );
+    }
+
+    #[test]
+    pub fn yearly_defaults_and_leap_days() {
+        let dates = expand("FREQ=YEARLY", "2020-02-29T00:00:00", 3);
+        assert_eq!(
+            dates,
+            vec![
+                "2020-02-29T00:00:00",
+                "2024-02-29T00:00:00",
+                "2028-02-29T00:00:00"
+            ]
+        );
+        let thanksgiving = expand("FREQ=YEARLY;BYMONTH=11;BYDAY=4TH", "2023-01-01T00:00:00", 2);
+        assert_eq!(
+            thanksgiving,
+            vec!["2023-11-23T00:00:00", "2024-11-28T00:00:00"]
+        );
+    }
+
+    #[test]
+    pub fn week_numbers_and_year_days() {
+        let dates = expand("FREQ=YEARLY;BYWEEKNO=1;BYDAY=MO", "2024-01-01T00:00:00", 3);
+        assert_eq!(
+            dates,
+            vec![
+                "2024-01-01T00:00:00",
+                "2024-12-30T00:00:00",
+                "2025-12-29T00:00:00"
+            ]
+        );
+        let dates = expand(
+            "FREQ=YEARLY;BYYEARDAY=1,-1;COUNT=3",
+            "2023-06-01T00:00:00",
+            5,
+        );
+        assert_eq!(
+            dates,
+            vec![
+                "2023-12-31T00:00:00",
+                "2024-01-01T00:00:00",
+                "2024-12-31T00:00:00"
+            ]
+        );
+    }
+
+    #[test]
+    pub fn sub_daily_rules_skip_whole_days() {
+        let dates = expand(
+            "FREQ=MINUTELY;INTERVAL=20;BYHOUR=9,10;BYMONTH=3",
+            "2024-02-28T00:00:00",
+            7,
+        );
+        assert_eq!(dates[0], "2024-03-01T09:00:00");
+        assert_eq!(dates[5], "2024-03-01T10:40:00");
+        assert_eq!(dates[6], "2024-03-02T09:00:00");
+        let dates = expand(
+            "FREQ=DAILY;BYMONTH=2;BYMONTHDAY=29",
+            "2021-01-01T08:00:00",
+            2,
+        );
+        assert_eq!(dates, vec!["2024-02-29T08:00:00", "2028-02-29T08:00:00"]);
+    }
+
+    #[test]
+    pub fn exdates_and_round_trip() {
+        let set = RRuleSet::try_from(
+            "DTSTART:20240101T090000\nRRULE:FREQ=DAILY;COUNT=4\nEXDATE:20240102T090000Z",
+        )
+        .unwrap();
+        let dates: Vec<Date> = set.iter().collect();
+        assert_eq!(
+            dates,
+            vec![
+                date("2024-01-01T09:00:00"),
+                date("2024-01-03T09:00:00"),
+                date("2024-01-04T09:00:00"),
+            ]
+        );
+        let set = RRuleSet::try_from(
+            "DTSTART;VALUE=DATE:20240101\nRRULE:FREQ=DAILY;COUNT=3\nEXDATE;VALUE=DATE:20240102",
+        )
+        .unwrap();
+        let dates: Vec<Date> = set.iter().collect();
+        assert_eq!(
+            dates,
+            vec![date("2024-01-01T00:00:00"), date("2024-01-03T00:00:00")]
+        );
+        let rule = "FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=-1FR,2TU;BYSETPOS=1;WKST=SU";
+        assert_eq!(RRule::try_from(rule).unwrap().to_string(), rule);
+    }
+
+    #[test]
+    pub fn rejects_invalid_rules() {
+        for rule in [
+            "BYDAY=MO",
+            "FREQ=DAILY;COUNT=2;UNTIL=20240101T000000Z",
+            "FREQ=WEEKLY;BYDAY=1MO",
+            "FREQ=MONTHLY;BYWEEKNO=2",
+            "FREQ=WEEKLY;BYMONTHDAY=3",
+            "FREQ=MONTHLY;BYMONTHDAY=0",
+            "FREQ=MONTHLY;BYSETPOS=1",
+            "FREQ=DAILY;INTERVAL=0",
+            "FREQ=DAILY;FREQ=DAILY",
+            "FREQ=MINUTELY;BYSECOND=60",
+        ] {
+            assert!(RRule::try_from(rule).is_err(), "{}", rule);
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use std::fmt::Formatter;
+
+use ::serde::de::{Error, Unexpected, Visitor};
+use ::serde::{Deserialize, Serialize};
+pub use ::serde::{Deserializer, Serializer};
+
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::iso::IsoDate;
+use crate::month::{Month, Season};
+use crate::time::{Day, Hour, Minute, Second};
+
+struct DateVisitor;
+
+impl Visitor<'_> for DateVisitor {
+    type Value = Date;
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("an ISO-8601 date time like 2023-12-24T12:12:12")
+    }
+    fn visit_str<E: Error>(self, value: &str) -> Result<Date, E> {
+        Date::try_from(value).map_err(|_err| E::invalid_value(Unexpected::Str(value), &self))
+    }
+}
+
+impl Serialize for Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&IsoDate(self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(DateVisitor)
+    }
+}
+
+fn deserialize_u8<'de, D: Deserializer<'de>, T>(
+    deserializer: D,
+    expected: &str,
+    convert: impl FnOnce(u8) -> Result<T, WBDLError>,
+) -> Result<T, D::Error> {
+    let value = u8::deserialize(deserializer)?;
+    convert(value)
+        .map_err(|_err| D::Error::invalid_value(Unexpected::Unsigned(value as u64), &expected))
+}
+
+impl Serialize for Month {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.ordinal() as u8)
+    }
+}
+
+impl<'de> Deserialize<'de> for Month {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_u8(deserializer, "a month from 1 to 12", |value| {
+            Month::try_from(value as usize)
+        })
+    }
+}
+
+impl Serialize for Season {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.ordinal() as u8)
+    }
+}
+
+impl<'de> Deserialize<'de> for Season {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_u8(deserializer, "a season from 1 to 4", |value| {
+            Season::try_from(value as usize)
+        })
+    }
+}
+
+impl Serialize for Day {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.value())
+    }
+}
+
+impl<'de> Deserialize<'de> for Day {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // without a month at hand only the widest range can be checked
+        deserialize_u8(deserializer, "a day from 1 to 31", |value| {
+            Day::try_from((value, 2000, Month::December))
+        })
+    }
+}
+
+impl Serialize for Hour {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.value())
+    }
+}
+
+impl<'de> Deserialize<'de> for Hour {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // the same bounds as Date::new, the component types alone allow one more
+        deserialize_u8(deserializer, "an hour from 0 to 23", |value| match value {
+            0..=23 => Hour::try_from(value),
+            _ => Err(WBDLError),
+        })
+    }
+}
+
+impl Serialize for Minute {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.value())
+    }
+}
+
+impl<'de> Deserialize<'de> for Minute {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // the same bounds as Date::new, the component types alone allow one more
+        deserialize_u8(deserializer, "a minute from 0 to 59", |value| match value {
+            0..=59 => Minute::try_from(value),
+            _ => Err(WBDLError),
+        })
+    }
+}
+
+impl Serialize for Second {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.value())
+    }
+}
+
+impl<'de> Deserialize<'de> for Second {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // the same bounds as Date::new, the component types alone allow one more
+        deserialize_u8(deserializer, "a second from 0 to 59", |value| match value {
+            0..=59 => Second::try_from(value),
+            _ => Err(WBDLError),
+        })
+    }
+}
+
+struct ErrorVisitor;
+
+impl Visitor<'_> for ErrorVisitor {
+    type Value = WBDLError;
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("unit struct WBDLError")
+    }
+    fn visit_unit<E: Error>(self) -> Result<WBDLError, E> {
+        Ok(WBDLError)
+    }
+}
+
+impl Serialize for WBDLError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit_struct("WBDLError")
+    }
+}
+
+impl<'de> Deserialize<'de> for WBDLError {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_unit_struct("WBDLError", ErrorVisitor)
+    }
+}
+
+// #[serde(with = "wbdl::serde::ts_seconds")]
+pub mod ts_seconds {
+    use ::serde::de::Error;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::date::Date;
+
+    pub fn serialize<S: Serializer>(date: &Date, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(date.timestamp())
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Date, D::Error> {
+        Date::from_timestamp(i64::deserialize(deserializer)?)
+            .map_err(|_err| D::Error::custom("timestamp out of range"))
+    }
+}
+
+// #[serde(with = "wbdl::serde::ts_milliseconds")], sub second precision is truncated
+pub mod ts_milliseconds {
+    use ::serde::de::Error;
+    use ::serde::ser::Error as _;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::date::Date;
+
+    pub fn serialize<S: Serializer>(date: &Date, serializer: S) -> Result<S::Ok, S::Error> {
+        let millis = date
+            .timestamp()
+            .checked_mul(1000)
+            .ok_or_else(|| S::Error::custom("timestamp out of range"))?;
+        serializer.serialize_i64(millis)
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Date, D::Error> {
+        Date::from_timestamp(i64::deserialize(deserializer)?.div_euclid(1000))
+            .map_err(|_err| D::Error::custom("timestamp out of range"))
+    }
+}
+
+// backs the modules generated by `serde_format!`
+pub mod format {
+    use ::serde::de::{Error, Unexpected, Visitor};
+    use ::serde::{Deserializer, Serializer};
+    use std::fmt::Formatter;
+
+    use crate::date::Date;
+
+    struct FormatVisitor<'a>(&'a str);
+
+    impl Visitor<'_> for FormatVisitor<'_> {
+        type Value = Date;
+        fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            write!(formatter, "a date time formatted as {}", self.0)
+        }
+        fn visit_str<E: Error>(self, value: &str) -> Result<Date, E> {
+            Date::parse_from_str(value, self.0)
+                .map_err(|_err| E::invalid_value(Unexpected::Str(value), &self))
+        }
+    }
+
+    pub fn serialize<S: Serializer>(
+        date: &Date,
+        pattern: &str,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let formatted = date
+            .format(pattern)
+            .map_err(|_err| ::serde::ser::Error::custom("invalid format pattern"))?;
+        serializer.serialize_str(&formatted)
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        pattern: &str,
+    ) -> Result<Date, D::Error> {
+        deserializer.deserialize_str(FormatVisitor(pattern))
+    }
+}
+
+// generates a module usable with #[serde(with = "...")] for a custom pattern:
+// wbdl::serde_format!(pub german, "%d.%m.%Y %H:%M");
+#[macro_export]
+macro_rules! serde_format {
+    ($vis:vis $name:ident, $pattern:expr) => {
+        $vis mod $name {
+            pub fn serialize<S: $crate::serde::Serializer>(
+                date: &$crate::Date,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                $crate::serde::format::serialize(date, $pattern, serializer)
+            }
+            pub fn deserialize<'de, D: $crate::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<$crate::Date, D::Error> {
+                $crate::serde::format::deserialize(deserializer, $pattern)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+
+    use crate::{Date, Day, Hour, Minute, Month, Second, WBDLError};
+
+    crate::serde_format!(german, "%d.%m.%Y %H:%M");
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Event {
+        start: Date,
+        #[serde(with = "crate::serde::ts_seconds")]
+        seconds: Date,
+        #[serde(with = "crate::serde::ts_milliseconds")]
+        millis: Date,
+        #[serde(with = "german")]
+        local: Date,
+    }
+
+    #[test]
+    pub fn date_as_iso_string() {
+        let date = Date::try_from("2023-12-24T12:12:12").unwrap();
+        assert_eq!(
+            serde_json::to_string(&date).unwrap(),
+            "\"2023-12-24T12:12:12\""
+        );
+        assert_eq!(
+            serde_json::from_str::<Date>("\"2023-12-24T12:12:12\"").unwrap(),
+            date
+        );
+        assert!(serde_json::from_str::<Date>("\"2023-02-30T12:12:12\"").is_err());
+        assert!(serde_json::from_str::<Date>("\"2023-12-24T24:60:60\"").is_err());
+        assert!(serde_json::from_str::<Date>("\"2023-12-24T12:12:60\"").is_err());
+        assert!(serde_json::from_str::<Date>("\"2023-December-24T12:12:12\"").is_err());
+        assert!(serde_json::from_str::<Date>("1703419932").is_err());
+    }
+
+    #[test]
+    pub fn alternate_encodings() {
+        let date = Date::try_from("2023-12-24T12:12:00").unwrap();
+        let event = Event {
+            start: date,
+            seconds: date,
+            millis: date,
+            local: date,
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            json,
+            "{\"start\":\"2023-12-24T12:12:00\",\"seconds\":1703419920,\
+             \"millis\":1703419920000,\"local\":\"24.12.2023 12:12\"}"
+        );
+        assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+    }
+
+    #[test]
+    pub fn components() {
+        assert_eq!(serde_json::to_string(&Month::March).unwrap(), "3");
+        assert_eq!(
+            serde_json::from_str::<Month>("12").unwrap(),
+            Month::December
+        );
+        assert!(serde_json::from_str::<Month>("13").is_err());
+        let day: Day = serde_json::from_str("31").unwrap();
+        assert_eq!(day.value(), 31);
+        assert!(serde_json::from_str::<Day>("0").is_err());
+        assert_eq!(serde_json::from_str::<Hour>("23").unwrap().value(), 23);
+        assert!(serde_json::from_str::<Hour>("24").is_err());
+        assert!(serde_json::from_str::<Minute>("60").is_err());
+        assert!(serde_json::from_str::<Second>("60").is_err());
+        assert_eq!(serde_json::to_string(&WBDLError).unwrap(), "null");
+        assert_eq!(
+            serde_json::from_str::<WBDLError>("null").unwrap(),
+            WBDLError
+        );
+    }
+}
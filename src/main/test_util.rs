@@ -0,0 +1,5 @@
+use crate::date::Date;
+
+pub fn date(value: &str) -> Date {
+    Date::try_from(value).unwrap()
+}
@@ -16,9 +16,18 @@ pub struct Second(u8);
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Default, Hash, Debug)]
 pub struct Day(u8);
 
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Meridiem {
+    Am,
+    Pm,
+}
+
 impl Hour {
     pub const MIN: Hour = Hour(0);
     pub const MAX: Hour = Hour(23);
+    pub const fn value(&self) -> u8 {
+        self.0
+    }
     pub fn next(&self) -> Hour {
         if self == &Hour::MAX {
             Hour::MIN
@@ -34,10 +43,42 @@ impl Hour {
             Hour(self.0 - 1)
         }
     }
+    pub const fn meridiem(&self) -> Meridiem {
+        if self.0 % 24 < 12 {
+            Meridiem::Am
+        } else {
+            Meridiem::Pm
+        }
+    }
+    // midnight is 12 AM and noon is 12 PM
+    pub const fn to_12h(&self) -> (u8, Meridiem) {
+        ((self.0 + 11) % 12 + 1, self.meridiem())
+    }
+    pub fn from_12h(hour: u8, meridiem: Meridiem) -> Result<Hour, WBDLError> {
+        if !(1..=12).contains(&hour) {
+            return Err(WBDLError);
+        }
+        Ok(Hour(match meridiem {
+            Meridiem::Am => hour % 12,
+            Meridiem::Pm => hour % 12 + 12,
+        }))
+    }
+}
+
+impl Meridiem {
+    pub const fn abbreviation(&self) -> &'static str {
+        match self {
+            Meridiem::Am => "AM",
+            Meridiem::Pm => "PM",
+        }
+    }
 }
 
 impl Day {
     pub const MIN: Day = Day(1);
+    pub const fn value(&self) -> u8 {
+        self.0
+    }
     pub fn max(year: u16, month: Month) -> Day {
         Self(Day::get_days_per_month(year)[month.ordinal()])
     }
@@ -83,6 +124,9 @@ impl Day {
 impl Minute {
     pub const MIN: Minute = Minute(0);
     pub const MAX: Minute = Minute(59);
+    pub const fn value(&self) -> u8 {
+        self.0
+    }
     pub fn next(&self) -> Minute {
         if self == &Minute::MAX {
             Minute::MIN
@@ -103,6 +147,9 @@ impl Minute {
 impl Second {
     pub const MIN: Second = Second(0);
     pub const MAX: Second = Second(59);
+    pub const fn value(&self) -> u8 {
+        self.0
+    }
 
     pub fn next(&self) -> Second {
         if self == &Second::MAX {
@@ -145,6 +192,31 @@ impl Display for Second {
     }
 }
 
+impl Display for Meridiem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.abbreviation())
+    }
+}
+
+// am, pm, a.m. and p.m. in any case
+impl TryFrom<&str> for Meridiem {
+    type Error = WBDLError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "am" | "a.m." => Ok(Meridiem::Am),
+            "pm" | "p.m." => Ok(Meridiem::Pm),
+            _ => Err(WBDLError),
+        }
+    }
+}
+
+impl FromStr for Meridiem {
+    type Err = WBDLError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Meridiem::try_from(s)
+    }
+}
+
 impl TryFrom<&str> for Hour {
     type Error = WBDLError;
     fn try_from(value: &str) -> Result<Self, Self::Error> {
@@ -192,7 +264,7 @@ impl TryFrom<u8> for Hour {
 impl TryFrom<(u8, u16, Month)> for Day {
     type Error = WBDLError;
     fn try_from(value: (u8, u16, Month)) -> Result<Self, Self::Error> {
-        if value.0 <= Day::get_days_per_month(value.1)[value.2.ordinal()] {
+        if value.0 >= Day::MIN.0 && value.0 <= Day::get_days_per_month(value.1)[value.2.ordinal()] {
             Ok(Self(value.0))
         } else {
             Err(WBDLError)
@@ -224,7 +296,7 @@ impl TryFrom<u8> for Second {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Date, Hour, Minute};
+    use crate::{Date, Day, Hour, Meridiem, Minute, Month};
 
     #[test]
     pub fn correct_next_hour() {
@@ -262,9 +334,36 @@ mod tests {
         assert_eq!(last.previous(), Minute::MAX);
     }
 
+    #[test]
+    pub fn twelve_hour_clock() {
+        let cases = [
+            (0, 12, Meridiem::Am),
+            (1, 1, Meridiem::Am),
+            (12, 12, Meridiem::Pm),
+            (23, 11, Meridiem::Pm),
+        ];
+        for (hour, twelve, meridiem) in cases {
+            let hour = Hour::try_from(hour).unwrap();
+            assert_eq!(hour.to_12h(), (twelve, meridiem));
+            assert_eq!(Hour::from_12h(twelve, meridiem), Ok(hour));
+        }
+        assert!(Hour::from_12h(0, Meridiem::Am).is_err());
+        assert!(Hour::from_12h(13, Meridiem::Pm).is_err());
+        assert_eq!("p.M.".parse(), Ok(Meridiem::Pm));
+        assert_eq!(Meridiem::Am.to_string(), "AM");
+        assert!("a".parse::<Meridiem>().is_err());
+    }
+
     #[test]
     pub fn weird_date_test() {
         let last = Date::try_from(String::from("2000-11-30T0:0:0")).unwrap();
         assert_eq!(last.add_day(), Date::try_from("2000-12-1T0:0:0").unwrap())
     }
+
+    #[test]
+    pub fn day_zero_is_rejected() {
+        assert!(Day::try_from((0, 2024, Month::January)).is_err());
+        assert!(Day::try_from((1, 2024, Month::January)).is_ok());
+        assert!(Date::try_from("2024-01-00T00:00:00").is_err());
+    }
 }
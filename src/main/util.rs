@@ -1,17 +1,25 @@
 use crate::time::Day;
 
-const SECONDS_PER_MINUTE: u8 = 60u8;
-const SECONDS_PER_HOUR: u16 = SECONDS_PER_MINUTE as u16 * SECONDS_PER_MINUTE as u16;
-const SECONDS_PER_DAY: u32 = 24u32 * SECONDS_PER_HOUR as u32;
+pub(crate) const SECONDS_PER_MINUTE: u8 = 60u8;
+pub(crate) const SECONDS_PER_HOUR: u16 = SECONDS_PER_MINUTE as u16 * SECONDS_PER_MINUTE as u16;
+pub(crate) const SECONDS_PER_DAY: u32 = 24u32 * SECONDS_PER_HOUR as u32;
 const DAYS_PER_YEAR: u16 = 365;
 const DAYS_PER_LEAP_YEAR: u16 = DAYS_PER_YEAR + 1;
 const EPOCH_MONTH: u8 = 1;
 pub(crate) const EPOCH_YEAR: u16 = 1970;
+// julian day number of 1970-01-01, that day starts at julian date 2440587.5
+pub const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+// the modified julian date starts at 1858-11-17T00:00:00
+pub const MODIFIED_JULIAN_DATE_OFFSET: f64 = 2_400_000.5;
 
 pub const fn is_leap_year(year: u16) -> bool {
     year % 400 == 0 || (year % 4 == 0 && year % 100 != 0)
 }
 
+pub const fn is_julian_leap_year(year: u16) -> bool {
+    year % 4 == 0
+}
+
 pub const fn get_days_for_year(year: u16) -> u16 {
     if is_leap_year(year) {
         DAYS_PER_LEAP_YEAR
@@ -48,6 +56,109 @@ pub const fn get_date_time(timestamp: u64) -> [u16; 6] {
     ]
 }
 
+// days since 1970-01-01 in the proleptic gregorian calendar
+pub const fn days_from_civil(year: u16, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 {
+        year as i64 - 1
+    } else {
+        year as i64
+    };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// inverse of days_from_civil, returns [year, month, day]
+pub const fn civil_from_days(days: i64) -> [i64; 3] {
+    let days = days + 719_468;
+    let era = if days >= 0 { days } else { days - 146_096 } / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    [year, month, day]
+}
+
+// days since 1970-01-01 (gregorian) of a date in the proleptic julian calendar
+pub const fn days_from_julian(year: u16, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 {
+        year as i64 - 1
+    } else {
+        year as i64
+    };
+    let month = month as i64;
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + day as i64 - 1;
+    year * 365 + year.div_euclid(4) + day_of_year - 719_470
+}
+
+// inverse of days_from_julian, returns [year, month, day]
+pub const fn julian_from_days(days: i64) -> [i64; 3] {
+    let days = days + 719_470;
+    let cycle = days.div_euclid(1461);
+    let day_of_cycle = days.rem_euclid(1461);
+    let year_of_cycle = (day_of_cycle - day_of_cycle / 1460) / 365;
+    let day_of_year = day_of_cycle - 365 * year_of_cycle;
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = cycle * 4 + year_of_cycle + if month <= 2 { 1 } else { 0 };
+    [year, month, day]
+}
+
+pub const fn julian_day_number(year: u16, month: u8, day: u8) -> i64 {
+    days_from_civil(year, month, day) + UNIX_EPOCH_JULIAN_DAY
+}
+
+// inverse of julian_day_number, returns [year, month, day]
+pub const fn civil_from_julian_day_number(julian_day: i64) -> [i64; 3] {
+    civil_from_days(julian_day - UNIX_EPOCH_JULIAN_DAY)
+}
+
+// gregorian easter sunday (anonymous algorithm), returns [month, day]
+pub const fn easter(year: u16) -> [u8; 2] {
+    let year = year as u32;
+    let golden = year % 19;
+    let (century, rest) = (year / 100, year % 100);
+    let epact = (19 * golden + century - century / 4 - (8 * century + 13) / 25 + 15) % 30;
+    let weekday = (32 + 2 * (century % 4) + 2 * (rest / 4) - epact - rest % 4) % 7;
+    let correction = (golden + 11 * epact + 22 * weekday) / 451;
+    let days = epact + weekday - 7 * correction + 114;
+    [(days / 31) as u8, (days % 31 + 1) as u8]
+}
+
+// easter sunday in the julian calendar (meeus), returns [month, day] as a julian date
+pub const fn julian_easter(year: u16) -> [u8; 2] {
+    let year = year as u32;
+    let epact = (19 * (year % 19) + 15) % 30;
+    let weekday = (2 * (year % 4) + 4 * (year % 7) + 34 - epact) % 7;
+    let days = epact + weekday + 114;
+    [(days / 31) as u8, (days % 31 + 1) as u8]
+}
+
+// orthodox easter, i.e. the julian easter expressed as a gregorian [month, day]
+pub const fn orthodox_easter(year: u16) -> [u8; 2] {
+    let [month, day] = julian_easter(year);
+    let [_, month, day] = civil_from_days(days_from_julian(year, month, day));
+    [month as u8, day as u8]
+}
+
 #[cfg(test)]
 mod test {
     use crate::util;
@@ -66,6 +177,85 @@ mod test {
         }
     }
 
+    #[test]
+    pub fn civil_round_trip() {
+        assert_eq!(util::days_from_civil(1970, 1, 1), 0);
+        assert_eq!(util::days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(util::days_from_civil(1969, 12, 31), -1);
+        for days in -700_000..800_000 {
+            let [year, month, day] = util::civil_from_days(days);
+            assert_eq!(
+                util::days_from_civil(year as u16, month as u8, day as u8),
+                days
+            );
+        }
+    }
+
+    #[test]
+    pub fn julian_day_number() {
+        assert_eq!(util::julian_day_number(2000, 1, 1), 2_451_545);
+        assert_eq!(util::julian_day_number(1858, 11, 17), 2_400_001);
+        assert_eq!(util::julian_day_number(1582, 10, 15), 2_299_161);
+        // fliegel and van flandern as an independent check of days_from_civil
+        for days in -700_000..800_000 {
+            let [year, month, day] = util::civil_from_days(days);
+            let a = (month - 14) / 12;
+            let expected = (1461 * (year + 4800 + a)) / 4 + (367 * (month - 2 - 12 * a)) / 12
+                - (3 * ((year + 4900 + a) / 100)) / 4
+                + day
+                - 32075;
+            assert_eq!(
+                util::julian_day_number(year as u16, month as u8, day as u8),
+                expected
+            );
+            assert_eq!(
+                util::civil_from_julian_day_number(expected),
+                [year, month, day]
+            );
+        }
+    }
+
+    #[test]
+    pub fn julian_calendar() {
+        assert_eq!(util::days_from_julian(1969, 12, 19), 0);
+        assert_eq!(
+            util::days_from_julian(1582, 10, 5),
+            util::days_from_civil(1582, 10, 15)
+        );
+        assert_eq!(
+            util::days_from_julian(1752, 9, 3),
+            util::days_from_civil(1752, 9, 14)
+        );
+        assert_eq!(
+            util::julian_from_days(util::days_from_civil(2024, 1, 14)),
+            [2024, 1, 1]
+        );
+        assert_eq!(
+            util::julian_from_days(util::days_from_civil(1900, 3, 13)),
+            [1900, 2, 29]
+        );
+        assert!(util::is_julian_leap_year(1900));
+        for days in -700_000..800_000 {
+            let [year, month, day] = util::julian_from_days(days);
+            assert_eq!(
+                util::days_from_julian(year as u16, month as u8, day as u8),
+                days
+            );
+        }
+    }
+
+    #[test]
+    pub fn easter() {
+        assert_eq!(util::easter(1818), [3, 22]);
+        assert_eq!(util::easter(2024), [3, 31]);
+        assert_eq!(util::easter(2025), [4, 20]);
+        assert_eq!(util::easter(2038), [4, 25]);
+        assert_eq!(util::julian_easter(2024), [4, 22]);
+        assert_eq!(util::orthodox_easter(2024), [5, 5]);
+        assert_eq!(util::orthodox_easter(2025), [4, 20]);
+        assert_eq!(util::orthodox_easter(2023), [4, 16]);
+    }
+
     pub fn get_leap_years() -> Vec<i32> {
         vec![
             1804, 1808, 1812, 1816, 1820, 1824, 1828, 1832, 1836, 1840, 1844, 1848, 1852, 1856,
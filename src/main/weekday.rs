@@ -0,0 +1,261 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::date::Date;
+use crate::error::WBDLError;
+use crate::month::Month;
+use crate::time::Day;
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Weekday {
+    Monday = 1,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    pub const MIN: Weekday = Weekday::Monday;
+    pub const MAX: Weekday = Weekday::Sunday;
+    pub fn ordinal(&self) -> usize {
+        *self as usize
+    }
+    pub const fn next(&self) -> Weekday {
+        match self {
+            Weekday::Monday => Weekday::Tuesday,
+            Weekday::Tuesday => Weekday::Wednesday,
+            Weekday::Wednesday => Weekday::Thursday,
+            Weekday::Thursday => Weekday::Friday,
+            Weekday::Friday => Weekday::Saturday,
+            Weekday::Saturday => Weekday::Sunday,
+            Weekday::Sunday => Weekday::Monday,
+        }
+    }
+    pub const fn previous(&self) -> Weekday {
+        match self {
+            Weekday::Monday => Weekday::Sunday,
+            Weekday::Tuesday => Weekday::Monday,
+            Weekday::Wednesday => Weekday::Tuesday,
+            Weekday::Thursday => Weekday::Wednesday,
+            Weekday::Friday => Weekday::Thursday,
+            Weekday::Saturday => Weekday::Friday,
+            Weekday::Sunday => Weekday::Saturday,
+        }
+    }
+    // days to go forward from self to reach other, 0 if they are equal
+    pub const fn days_until(&self, other: Weekday) -> u8 {
+        (other as u8 + 7 - *self as u8) % 7
+    }
+    pub const fn abbreviation(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "Mon",
+            Weekday::Tuesday => "Tue",
+            Weekday::Wednesday => "Wed",
+            Weekday::Thursday => "Thu",
+            Weekday::Friday => "Fri",
+            Weekday::Saturday => "Sat",
+            Weekday::Sunday => "Sun",
+        }
+    }
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+    }
+    // english three letter abbreviation, case insensitive
+    pub fn from_abbreviation(value: &str) -> Result<Weekday, WBDLError> {
+        (1..=7)
+            .filter_map(|weekday| Weekday::try_from(weekday).ok())
+            .find(|weekday| weekday.abbreviation().eq_ignore_ascii_case(value))
+            .ok_or(WBDLError)
+    }
+    // full or abbreviated english name, case insensitive
+    pub fn from_name(value: &str) -> Result<Weekday, WBDLError> {
+        (1..=7)
+            .filter_map(|weekday| Weekday::try_from(weekday).ok())
+            .find(|weekday| weekday.name().eq_ignore_ascii_case(value))
+            .map_or_else(|| Weekday::from_abbreviation(value), Ok)
+    }
+    pub(crate) const fn from_days(days: i64) -> Weekday {
+        // 1970-01-01 was a thursday
+        match (days + 3).rem_euclid(7) {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+}
+
+impl TryFrom<usize> for Weekday {
+    type Error = WBDLError;
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Weekday::Monday),
+            2 => Ok(Weekday::Tuesday),
+            3 => Ok(Weekday::Wednesday),
+            4 => Ok(Weekday::Thursday),
+            5 => Ok(Weekday::Friday),
+            6 => Ok(Weekday::Saturday),
+            7 => Ok(Weekday::Sunday),
+            _ => Err(WBDLError),
+        }
+    }
+}
+
+// the iso number of the weekday or its english name
+impl TryFrom<&str> for Weekday {
+    type Error = WBDLError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match usize::from_str(value) {
+            Ok(number) => Weekday::try_from(number),
+            Err(_err) => Weekday::from_name(value),
+        }
+    }
+}
+
+impl FromStr for Weekday {
+    type Err = WBDLError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Weekday::try_from(s)
+    }
+}
+
+impl Display for Weekday {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl Date {
+    pub fn nth_weekday_of_month(
+        year: u16,
+        month: Month,
+        weekday: Weekday,
+        n: u8,
+    ) -> Result<Date, WBDLError> {
+        if n == 0 {
+            return Err(WBDLError);
+        }
+        let first = Date::new(year, month, 1, 0, 0, 0)?;
+        let day = 1 + first.weekday().days_until(weekday) as u16 + (n as u16 - 1) * 7;
+        if day > Day::max(year, month).value() as u16 {
+            return Err(WBDLError);
+        }
+        Date::new(year, month, day as u8, 0, 0, 0)
+    }
+    pub fn last_weekday_of_month(
+        year: u16,
+        month: Month,
+        weekday: Weekday,
+    ) -> Result<Date, WBDLError> {
+        let last = Date::new(year, month, Day::max(year, month).value(), 0, 0, 0)?;
+        last.add_days(-(weekday.days_until(last.weekday()) as i64))
+    }
+    // strictly after self, keeping the time of day
+    pub fn next_weekday(&self, weekday: Weekday) -> Result<Date, WBDLError> {
+        let days = match self.weekday().days_until(weekday) {
+            0 => 7,
+            days => days,
+        };
+        self.add_days(days as i64)
+    }
+    // strictly before self, keeping the time of day
+    pub fn previous_weekday(&self, weekday: Weekday) -> Result<Date, WBDLError> {
+        let days = match weekday.days_until(self.weekday()) {
+            0 => 7,
+            days => days,
+        };
+        self.add_days(-(days as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, Month, Weekday};
+
+    #[test]
+    pub fn weekday_of_date() {
+        assert_eq!(Date::UNIX_EPOCH.weekday(), Weekday::Thursday);
+        let christmas_eve = Date::try_from("2023-12-24T12:12:12").unwrap();
+        assert_eq!(christmas_eve.weekday(), Weekday::Sunday);
+        let before_epoch = Date::try_from("1969-12-29T00:00:00").unwrap();
+        assert_eq!(before_epoch.weekday(), Weekday::Monday);
+    }
+
+    #[test]
+    pub fn weekday_of_month() {
+        let date = |value| Date::try_from(value).unwrap();
+        assert_eq!(
+            Date::nth_weekday_of_month(2024, Month::January, Weekday::Monday, 3),
+            Ok(date("2024-01-15T00:00:00"))
+        );
+        assert_eq!(
+            Date::nth_weekday_of_month(2024, Month::February, Weekday::Thursday, 5),
+            Ok(date("2024-02-29T00:00:00"))
+        );
+        assert!(Date::nth_weekday_of_month(2023, Month::February, Weekday::Thursday, 5).is_err());
+        assert_eq!(
+            Date::last_weekday_of_month(2024, Month::May, Weekday::Friday),
+            Ok(date("2024-05-31T00:00:00"))
+        );
+        assert_eq!(
+            Date::last_weekday_of_month(2024, Month::February, Weekday::Friday),
+            Ok(date("2024-02-23T00:00:00"))
+        );
+    }
+
+    #[test]
+    pub fn weekday_navigation() {
+        let wednesday = Date::try_from("2024-01-17T08:30:00").unwrap();
+        let next_monday = wednesday.next_weekday(Weekday::Monday).unwrap();
+        assert_eq!(next_monday, Date::try_from("2024-01-22T08:30:00").unwrap());
+        let next_wednesday = wednesday.next_weekday(Weekday::Wednesday).unwrap();
+        assert_eq!(
+            next_wednesday,
+            Date::try_from("2024-01-24T08:30:00").unwrap()
+        );
+        let previous_friday = wednesday.previous_weekday(Weekday::Friday).unwrap();
+        assert_eq!(
+            previous_friday,
+            Date::try_from("2024-01-12T08:30:00").unwrap()
+        );
+        let previous_wednesday = wednesday.previous_weekday(Weekday::Wednesday).unwrap();
+        assert_eq!(
+            previous_wednesday,
+            Date::try_from("2024-01-10T08:30:00").unwrap()
+        );
+    }
+
+    #[test]
+    pub fn days_until() {
+        assert_eq!(Weekday::Friday.days_until(Weekday::Monday), 3);
+        assert_eq!(Weekday::Monday.days_until(Weekday::Friday), 4);
+        assert_eq!(Weekday::Monday.days_until(Weekday::Monday), 0);
+    }
+
+    #[test]
+    pub fn correct_next_and_previous() {
+        assert_eq!(Weekday::MAX.next(), Weekday::MIN);
+        assert_eq!(Weekday::MIN.previous(), Weekday::MAX);
+        assert_eq!(Weekday::try_from(3), Ok(Weekday::Wednesday));
+        assert!(Weekday::try_from(8).is_err());
+        assert_eq!(Weekday::from_abbreviation("SUN"), Ok(Weekday::Sunday));
+        assert_eq!(Weekday::Thursday.abbreviation(), "Thu");
+        assert_eq!("wednesday".parse(), Ok(Weekday::Wednesday));
+        assert_eq!(Weekday::try_from("7"), Ok(Weekday::Sunday));
+        assert_eq!(Weekday::Friday.to_string(), "Friday");
+    }
+}